@@ -1,5 +1,6 @@
 use crate::{assert_that, Asserter};
 
+#[allow(clippy::multiple_inherent_impl)]
 impl<AssertedType> Asserter<AssertedType> {
     /// Extracts the value of an asserted value
     ///
@@ -1,11 +1,24 @@
+pub mod ansi;
 pub mod boolean;
+pub mod bytes;
+pub mod captures;
+pub mod duration;
 pub mod equality;
 pub mod file;
+pub mod format;
+pub mod integer;
 pub mod iter;
 pub mod option;
 pub mod path;
 pub mod result;
 pub mod string;
+pub mod time;
 
 #[cfg(feature = "json")]
 pub mod json;
+
+#[cfg(feature = "chrono")]
+pub mod chrono;
+
+#[cfg(feature = "time")]
+pub mod time_crate;
@@ -0,0 +1,298 @@
+use std::fmt::{self, Debug};
+
+/// A style of text rendered by ANSI escape sequences in a terminal
+///
+/// Used by [`has_ansi_style`](crate::StringAssertion::has_ansi_style).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum AnsiStyle {
+    /// Bold or increased intensity (`ESC[1m`)
+    Bold,
+    /// Faint or decreased intensity (`ESC[2m`)
+    Dimmed,
+    /// `ESC[3m`
+    Italic,
+    /// `ESC[4m`
+    Underline,
+    /// `ESC[5m` or `ESC[6m`
+    Blink,
+    /// Swapped foreground and background colors (`ESC[7m`)
+    Reversed,
+    /// `ESC[8m`
+    Hidden,
+    /// `ESC[9m`
+    Strikethrough,
+    /// The color of the text itself
+    Foreground(AnsiColor),
+    /// The color behind the text
+    Background(AnsiColor),
+}
+
+/// A color of text rendered by ANSI escape sequences in a terminal
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum AnsiColor {
+    /// Color 0 of the standard palette
+    Black,
+    /// Color 1 of the standard palette
+    Red,
+    /// Color 2 of the standard palette
+    Green,
+    /// Color 3 of the standard palette
+    Yellow,
+    /// Color 4 of the standard palette
+    Blue,
+    /// Color 5 of the standard palette
+    Magenta,
+    /// Color 6 of the standard palette
+    Cyan,
+    /// Color 7 of the standard palette
+    White,
+    /// Color 8 of the standard palette
+    BrightBlack,
+    /// Color 9 of the standard palette
+    BrightRed,
+    /// Color 10 of the standard palette
+    BrightGreen,
+    /// Color 11 of the standard palette
+    BrightYellow,
+    /// Color 12 of the standard palette
+    BrightBlue,
+    /// Color 13 of the standard palette
+    BrightMagenta,
+    /// Color 14 of the standard palette
+    BrightCyan,
+    /// Color 15 of the standard palette
+    BrightWhite,
+    /// A color of the 256 color palette (`ESC[38;5;<n>m`) which is not part of the standard palette
+    Fixed(u8),
+    /// A true color (`ESC[38;2;<r>;<g>;<b>m`)
+    Rgb(u8, u8, u8),
+}
+
+const PALETTE: [AnsiColor; 16] = [
+    AnsiColor::Black,
+    AnsiColor::Red,
+    AnsiColor::Green,
+    AnsiColor::Yellow,
+    AnsiColor::Blue,
+    AnsiColor::Magenta,
+    AnsiColor::Cyan,
+    AnsiColor::White,
+    AnsiColor::BrightBlack,
+    AnsiColor::BrightRed,
+    AnsiColor::BrightGreen,
+    AnsiColor::BrightYellow,
+    AnsiColor::BrightBlue,
+    AnsiColor::BrightMagenta,
+    AnsiColor::BrightCyan,
+    AnsiColor::BrightWhite,
+];
+
+impl AnsiColor {
+    /// The color with the index of the 256 color palette. The first 16 colors are the named ones.
+    fn from_index(index: u8) -> Self {
+        PALETTE
+            .get(usize::from(index))
+            .copied()
+            .unwrap_or(Self::Fixed(index))
+    }
+}
+
+/// The styles active for a character of the rendered text
+#[derive(Clone, Default, PartialEq, Eq)]
+pub struct Styles(Vec<AnsiStyle>);
+
+impl Styles {
+    pub fn contains(&self, style: AnsiStyle) -> bool {
+        self.0.contains(&style)
+    }
+
+    /// The styles shared by both
+    pub fn intersection(&self, other: &Self) -> Self {
+        Self(
+            self.0
+                .iter()
+                .copied()
+                .filter(|style| other.contains(*style))
+                .collect(),
+        )
+    }
+
+    fn set(&mut self, style: AnsiStyle) {
+        self.unset(|active| same_kind(*active, style));
+        self.0.push(style);
+    }
+
+    fn unset(&mut self, predicate: impl Fn(&AnsiStyle) -> bool) {
+        self.0.retain(|active| !predicate(active));
+    }
+
+    /// Applies the parameters of a SGR (select graphic rendition) sequence
+    fn apply(&mut self, parameters: &str) {
+        let mut groups = parameters.split(';');
+
+        while let Some(group) = groups.next() {
+            let mut fields = group.split(':');
+            // unparsable codes are skipped instead of being mistaken for a reset
+            let Some(code) = fields.next().and_then(parse_code) else {
+                continue;
+            };
+
+            match code {
+                0 => self.0.clear(),
+                1 => self.set(AnsiStyle::Bold),
+                2 => self.set(AnsiStyle::Dimmed),
+                3 => self.set(AnsiStyle::Italic),
+                4 => self.set(AnsiStyle::Underline),
+                5 | 6 => self.set(AnsiStyle::Blink),
+                7 => self.set(AnsiStyle::Reversed),
+                8 => self.set(AnsiStyle::Hidden),
+                9 => self.set(AnsiStyle::Strikethrough),
+                21 | 22 => self.unset(|style| matches!(style, AnsiStyle::Bold | AnsiStyle::Dimmed)),
+                23 => self.unset(|style| *style == AnsiStyle::Italic),
+                24 => self.unset(|style| *style == AnsiStyle::Underline),
+                25 => self.unset(|style| *style == AnsiStyle::Blink),
+                27 => self.unset(|style| *style == AnsiStyle::Reversed),
+                28 => self.unset(|style| *style == AnsiStyle::Hidden),
+                29 => self.unset(|style| *style == AnsiStyle::Strikethrough),
+                30..=37 => self.set(AnsiStyle::Foreground(AnsiColor::from_index(code - 30))),
+                90..=97 => self.set(AnsiStyle::Foreground(AnsiColor::from_index(code - 82))),
+                40..=47 => self.set(AnsiStyle::Background(AnsiColor::from_index(code - 40))),
+                100..=107 => self.set(AnsiStyle::Background(AnsiColor::from_index(code - 92))),
+                38 => {
+                    if let Some(color) = extended_color(fields, &mut groups) {
+                        self.set(AnsiStyle::Foreground(color));
+                    }
+                }
+                48 => {
+                    if let Some(color) = extended_color(fields, &mut groups) {
+                        self.set(AnsiStyle::Background(color));
+                    }
+                }
+                39 => self.unset(|style| matches!(style, AnsiStyle::Foreground(_))),
+                49 => self.unset(|style| matches!(style, AnsiStyle::Background(_))),
+                _ => {}
+            }
+        }
+    }
+}
+
+impl Debug for Styles {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_list().entries(&self.0).finish()
+    }
+}
+
+/// Whether the styles exclude each other like two foreground colors
+fn same_kind(first: AnsiStyle, second: AnsiStyle) -> bool {
+    match (first, second) {
+        (AnsiStyle::Foreground(_), AnsiStyle::Foreground(_))
+        | (AnsiStyle::Background(_), AnsiStyle::Background(_)) => true,
+        _ => first == second,
+    }
+}
+
+/// Parses a SGR code, omitted codes like in `ESC[m` are treated as 0
+fn parse_code(code: &str) -> Option<u8> {
+    if code.is_empty() {
+        Some(0)
+    } else {
+        code.parse().ok()
+    }
+}
+
+/// Parses the color after the 38 or 48
+///
+/// Supports the semicolon form `38;5;<n>` and `38;2;<r>;<g>;<b>` as well as the colon form
+/// `38:5:<n>` and `38:2:[<colorspace>]:<r>:<g>:<b>` with an optional colorspace.
+fn extended_color<'a>(
+    subparameters: impl Iterator<Item = &'a str>,
+    groups: &mut impl Iterator<Item = &'a str>,
+) -> Option<AnsiColor> {
+    let fields: Vec<&str> = subparameters.collect();
+
+    match fields.as_slice() {
+        [] => {
+            match groups.next()? {
+                "5" => Some(AnsiColor::from_index(groups.next()?.parse().ok()?)),
+                "2" => {
+                    Some(AnsiColor::Rgb(
+                        groups.next()?.parse().ok()?,
+                        groups.next()?.parse().ok()?,
+                        groups.next()?.parse().ok()?,
+                    ))
+                }
+                _ => None,
+            }
+        }
+        ["5", index] => Some(AnsiColor::from_index(index.parse().ok()?)),
+        ["2", _, red, green, blue] | ["2", red, green, blue] => {
+            Some(AnsiColor::Rgb(
+                red.parse().ok()?,
+                green.parse().ok()?,
+                blue.parse().ok()?,
+            ))
+        }
+        _ => None,
+    }
+}
+
+/// Text with all escape sequences removed and the styles of its characters
+pub struct Rendered {
+    pub text: String,
+    /// The styles of each character of the text
+    pub styles: Vec<Styles>,
+}
+
+/// Removes all CSI, OSC and other escape sequences and keeps track of the styles set by SGR sequences
+pub fn render(value: &str) -> Rendered {
+    let mut text = String::with_capacity(value.len());
+    let mut styles = Vec::new();
+    let mut current = Styles::default();
+    let mut characters = value.chars().peekable();
+
+    while let Some(character) = characters.next() {
+        if character != '\u{1b}' {
+            text.push(character);
+            styles.push(current.clone());
+            continue;
+        }
+
+        match characters.next() {
+            // CSI: parameters and intermediate bytes followed by a final byte
+            Some('[') => {
+                let mut parameters = String::new();
+                for next in characters.by_ref() {
+                    if ('\u{40}'..='\u{7e}').contains(&next) {
+                        if next == 'm' {
+                            current.apply(&parameters);
+                        }
+                        break;
+                    }
+                    parameters.push(next);
+                }
+            }
+            // OSC: terminated by BEL or ST (`ESC \`)
+            Some(']') => {
+                while let Some(next) = characters.next() {
+                    if next == '\u{7}'
+                        || (next == '\u{1b}' && characters.next_if_eq(&'\\').is_some())
+                    {
+                        break;
+                    }
+                }
+            }
+            // nF sequences like `ESC ( B`: intermediate bytes followed by a final byte
+            Some(' '..='/') => {
+                while characters
+                    .next_if(|next| (' '..='/').contains(next))
+                    .is_some()
+                {}
+                characters.next();
+            }
+            // other escape sequences consist of a single character
+            _ => {}
+        }
+    }
+
+    Rendered { text, styles }
+}
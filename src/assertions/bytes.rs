@@ -0,0 +1,221 @@
+use crate::{
+    implementation::{self, Displayed, Hex},
+    private, Asserter,
+};
+use std::fmt::Write;
+
+/// Specifies various assertions on byte slices like [`Vec<u8>`] or `&[u8]`. Implemented on [`Asserter`]
+///
+/// Bytes are rendered as hex in the failure output.
+///
+/// This trait is sealed and cannot be implemented outside Smoothy.
+pub trait BytesAssertion<Bytes>: private::Sealed
+where
+    Bytes: AsRef<[u8]>,
+{
+    /// Asserts that the bytes are equal to the expected ones
+    ///
+    /// In contrast to [`equals`](crate::EqualityAssertion::equals) the failure shows a side-by-side hexdump
+    /// of the rows containing differing bytes. Bytes are compared position by position.
+    ///
+    /// # Examples
+    /// ```
+    /// # use smoothy::prelude::*;
+    /// #
+    /// assert_that(vec![0xCA, 0xFE, 0xBA, 0xBE]).equals_bytes([0xCA, 0xFE, 0xBA, 0xBE]);
+    /// ```
+    ///
+    /// ```should_panic
+    /// # use smoothy::prelude::*;
+    /// #
+    /// assert_that(vec![0xCA, 0xFE, 0xBA, 0xBE]).equals_bytes(b"cafe");
+    /// ```
+    ///
+    /// # Panics
+    /// When the bytes differ
+    #[track_caller]
+    fn equals_bytes(self, expected: impl AsRef<[u8]>) -> Asserter<Bytes>;
+
+    /// Asserts that the bytes start with the expected ones
+    ///
+    /// # Examples
+    /// ```
+    /// # use smoothy::prelude::*;
+    /// #
+    /// assert_that(b"\x89PNG\r\n\x1a\n...").starts_with_bytes(b"\x89PNG");
+    /// ```
+    ///
+    /// ```should_panic
+    /// # use smoothy::prelude::*;
+    /// #
+    /// assert_that(b"GIF89a...").starts_with_bytes(b"\x89PNG");
+    /// ```
+    ///
+    /// # Panics
+    /// When the bytes do not start with the expected ones
+    #[track_caller]
+    fn starts_with_bytes(self, expected: impl AsRef<[u8]>) -> Asserter<Bytes>;
+
+    /// Asserts that the bytes end with the expected ones
+    ///
+    /// # Examples
+    /// ```
+    /// # use smoothy::prelude::*;
+    /// #
+    /// assert_that(vec![0x01, 0x02, 0x0D, 0x0A]).ends_with_bytes(b"\r\n");
+    /// ```
+    ///
+    /// ```should_panic
+    /// # use smoothy::prelude::*;
+    /// #
+    /// assert_that(vec![0x01, 0x02, 0x0A]).ends_with_bytes(b"\r\n");
+    /// ```
+    ///
+    /// # Panics
+    /// When the bytes do not end with the expected ones
+    #[track_caller]
+    fn ends_with_bytes(self, expected: impl AsRef<[u8]>) -> Asserter<Bytes>;
+
+    /// Asserts that the bytes contain the expected ones consecutively
+    ///
+    /// # Examples
+    /// ```
+    /// # use smoothy::prelude::*;
+    /// #
+    /// assert_that(vec![0x00, 0xDE, 0xAD, 0xBE, 0xEF, 0x00]).contains_bytes([0xBE, 0xEF]);
+    /// ```
+    ///
+    /// ```should_panic
+    /// # use smoothy::prelude::*;
+    /// #
+    /// assert_that(vec![0x00, 0xDE, 0xAD, 0xBE, 0xEF, 0x00]).contains_bytes([0xEF, 0xBE]);
+    /// ```
+    ///
+    /// # Panics
+    /// When the bytes do not contain the expected ones
+    #[track_caller]
+    fn contains_bytes(self, expected: impl AsRef<[u8]>) -> Asserter<Bytes>;
+
+    /// Asserts that the bytes are valid UTF-8 and converts them to a [`String`] for further assertions
+    ///
+    /// # Examples
+    /// ```
+    /// # use smoothy::prelude::*;
+    /// #
+    /// assert_that(vec![0x47, 0x72, 0xC3, 0xBC, 0xC3, 0x9F, 0x65])
+    ///     .is_valid_utf8()
+    ///     .equals("Grüße");
+    /// ```
+    ///
+    /// ```should_panic
+    /// # use smoothy::prelude::*;
+    /// #
+    /// let _string = assert_that(vec![0x47, 0x72, 0xC3]).is_valid_utf8();
+    /// ```
+    ///
+    /// # Panics
+    /// When the bytes are not valid UTF-8
+    #[track_caller]
+    #[allow(clippy::wrong_self_convention)]
+    #[must_use = "Transforming the asserted value only asserts that it is valid UTF-8"]
+    fn is_valid_utf8(self) -> Asserter<String>;
+
+    /// Convenience function for converting the bytes to lowercase hex without separators
+    ///
+    /// # Examples
+    /// ```
+    /// # use smoothy::prelude::*;
+    /// #
+    /// assert_that(vec![0xCA, 0xFE, 0xBA, 0xBE])
+    ///     .as_hex()
+    ///     .equals("cafebabe");
+    /// ```
+    #[track_caller]
+    #[allow(clippy::wrong_self_convention)]
+    #[must_use = "Transforming the asserted value does not assert anything"]
+    fn as_hex(self) -> Asserter<String>;
+}
+
+impl<Bytes> BytesAssertion<Bytes> for Asserter<Bytes>
+where
+    Bytes: AsRef<[u8]>,
+{
+    fn equals_bytes(self, expected: impl AsRef<[u8]>) -> Self {
+        implementation::assert_equals_bytes(self.value.as_ref(), expected.as_ref());
+
+        self
+    }
+
+    fn starts_with_bytes(self, expected: impl AsRef<[u8]>) -> Self {
+        let actual = self.value.as_ref();
+
+        implementation::assert(
+            actual.starts_with(expected.as_ref()),
+            Hex(actual),
+            "to start with",
+            Hex(expected.as_ref()),
+        );
+
+        self
+    }
+
+    fn ends_with_bytes(self, expected: impl AsRef<[u8]>) -> Self {
+        let actual = self.value.as_ref();
+
+        implementation::assert(
+            actual.ends_with(expected.as_ref()),
+            Hex(actual),
+            "to end with",
+            Hex(expected.as_ref()),
+        );
+
+        self
+    }
+
+    fn contains_bytes(self, expected: impl AsRef<[u8]>) -> Self {
+        let actual = self.value.as_ref();
+        let needle = expected.as_ref();
+
+        implementation::assert(
+            needle.is_empty() || actual.windows(needle.len()).any(|window| window == needle),
+            Hex(actual),
+            "to contain",
+            Hex(needle),
+        );
+
+        self
+    }
+
+    fn is_valid_utf8(self) -> Asserter<String> {
+        let actual = self.value.as_ref();
+        let result = std::str::from_utf8(actual);
+
+        if let Err(error) = &result {
+            implementation::assert_with_additional_info_no_expected(
+                false,
+                Hex(actual),
+                "to be valid UTF-8",
+                "but decoding failed with",
+                Displayed(error),
+            );
+        }
+
+        Asserter {
+            value: String::from(result.unwrap_or_default()),
+        }
+    }
+
+    fn as_hex(self) -> Asserter<String> {
+        let bytes = self.value.as_ref();
+
+        Asserter {
+            // writing into a String cannot fail
+            value: bytes
+                .iter()
+                .try_fold(String::with_capacity(bytes.len() * 2), |mut hex, byte| {
+                    write!(hex, "{byte:02x}").map(|()| hex)
+                })
+                .unwrap_or_default(),
+        }
+    }
+}
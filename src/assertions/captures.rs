@@ -0,0 +1,240 @@
+use crate::{implementation, private, Asserter};
+use std::{
+    collections::BTreeMap,
+    fmt::{self, Debug},
+};
+
+/// The groups captured when matching a string-like against a pattern
+///
+/// Created by [`captures`](crate::StringAssertion::captures) or [`matches_template`](crate::StringAssertion::matches_template)
+/// and asserted with [`CapturesAssertion`].
+pub struct Captures {
+    groups: Vec<Group>,
+}
+
+struct Group {
+    name: Option<String>,
+    value: Option<String>,
+}
+
+impl Captures {
+    #[cfg(feature = "regex")]
+    pub(crate) fn from_regex(regex: &regex::Regex, captures: &regex::Captures<'_>) -> Self {
+        let groups = regex
+            .capture_names()
+            .zip(captures.iter())
+            .map(|(name, value)| {
+                Group {
+                    name: name.map(String::from),
+                    value: value.map(|matched| String::from(matched.as_str())),
+                }
+            })
+            .collect();
+
+        Self { groups }
+    }
+
+    /// The named placeholders of a template in their order starting at position 0
+    ///
+    /// In contrast to a regex there is no group for the whole match as no placeholder produced it.
+    pub(crate) fn from_template(placeholders: Vec<(String, String)>) -> Self {
+        let groups = placeholders
+            .into_iter()
+            .map(|(name, value)| {
+                Group {
+                    name: Some(name),
+                    value: Some(value),
+                }
+            })
+            .collect();
+
+        Self { groups }
+    }
+}
+
+impl Debug for Captures {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut map = f.debug_map();
+
+        for (index, group) in self.groups.iter().enumerate() {
+            match &group.name {
+                Some(name) => map.entry(&format_args!("{index} ({name})"), &group.value),
+                None => map.entry(&index, &group.value),
+            };
+        }
+
+        map.finish()
+    }
+}
+
+/// Specifies assertions on captured groups. Implemented on [`Asserter`]
+///
+/// This trait is sealed and cannot be implemented outside Smoothy.
+pub trait CapturesAssertion: private::Sealed {
+    /// Convenience function for accessing the value of a group by its position.
+    ///
+    /// The group at position 0 is the whole match of a regex.
+    /// Templates only capture their named placeholders starting at position 0.
+    ///
+    /// # Examples
+    /// ```
+    /// # use smoothy::prelude::*;
+    /// # use regex::Regex;
+    /// #
+    /// let regex = Regex::new(r"user-(\d+)").unwrap();
+    ///
+    /// assert_that("created user-42")
+    ///     .captures(&regex)
+    ///     .group(1)
+    ///     .equals("42");
+    /// ```
+    ///
+    /// ```should_panic
+    /// # use smoothy::prelude::*;
+    /// # use regex::Regex;
+    /// #
+    /// let regex = Regex::new(r"user-(\d+)").unwrap();
+    ///
+    /// assert_that("created user-42")
+    ///     .captures(&regex)
+    ///     .group(2)
+    ///     .equals("42");
+    /// ```
+    ///
+    /// # Panics
+    /// When there is no group at this position or the group did not participate in the match
+    #[track_caller]
+    #[must_use = "Accessing a group only asserts that it was captured"]
+    fn group(self, index: usize) -> Asserter<String>;
+
+    /// Convenience function for accessing the value of a group by its name.
+    ///
+    /// # Examples
+    /// ```
+    /// # use smoothy::prelude::*;
+    /// # use regex::Regex;
+    /// #
+    /// let regex = Regex::new(r"user-(?<id>\d+)").unwrap();
+    ///
+    /// assert_that("created user-42")
+    ///     .captures(&regex)
+    ///     .name("id")
+    ///     .equals("42");
+    /// ```
+    ///
+    /// ```should_panic
+    /// # use smoothy::prelude::*;
+    /// # use regex::Regex;
+    /// #
+    /// let regex = Regex::new(r"user-(?<id>\d+)").unwrap();
+    ///
+    /// assert_that("created user-42")
+    ///     .captures(&regex)
+    ///     .name("name")
+    ///     .equals("42");
+    /// ```
+    ///
+    /// # Panics
+    /// When there is no group with this name or the group did not participate in the match
+    #[track_caller]
+    #[must_use = "Accessing a group only asserts that it was captured"]
+    fn name(self, name: &str) -> Asserter<String>;
+
+    /// Convenience function for getting the number of groups.
+    ///
+    /// Groups of a regex include the whole match at position 0, those of a template only the named placeholders.
+    ///
+    /// # Examples
+    /// ```
+    /// # use smoothy::prelude::*;
+    /// # use regex::Regex;
+    /// #
+    /// let regex = Regex::new(r"(\w+)@(\w+)").unwrap();
+    ///
+    /// assert_that("me@host").captures(&regex).group_count().is(3);
+    /// ```
+    #[track_caller]
+    #[must_use = "Accessing the number of groups does not assert anything"]
+    fn group_count(self) -> Asserter<usize>;
+
+    /// Convenience function for accessing the values of all named groups by their name.
+    ///
+    /// Groups which did not participate in the match are left out.
+    ///
+    /// # Examples
+    /// ```
+    /// # use smoothy::prelude::*;
+    /// # use std::collections::BTreeMap;
+    /// #
+    /// assert_that("Created order 7f3a9c at 13:37")
+    ///     .matches_template("Created order {id} at {time}")
+    ///     .named_groups()
+    ///     .is(BTreeMap::from([
+    ///         (String::from("id"), String::from("7f3a9c")),
+    ///         (String::from("time"), String::from("13:37")),
+    ///     ]));
+    /// ```
+    #[track_caller]
+    #[must_use = "Accessing the named groups does not assert anything"]
+    fn named_groups(self) -> Asserter<BTreeMap<String, String>>;
+}
+
+impl CapturesAssertion for Asserter<Captures> {
+    fn group(self, index: usize) -> Asserter<String> {
+        let maybe_value = self
+            .value
+            .groups
+            .get(index)
+            .and_then(|group| group.value.clone());
+
+        implementation::assert(
+            maybe_value.is_some(),
+            &self.value,
+            "to have captured the group",
+            index,
+        );
+
+        #[allow(clippy::unwrap_used)]
+        let value = maybe_value.unwrap();
+
+        Asserter { value }
+    }
+
+    fn name(self, name: &str) -> Asserter<String> {
+        let maybe_value = self
+            .value
+            .groups
+            .iter()
+            .find(|group| group.name.as_deref() == Some(name))
+            .and_then(|group| group.value.clone());
+
+        implementation::assert(
+            maybe_value.is_some(),
+            &self.value,
+            "to have captured the group",
+            name,
+        );
+
+        #[allow(clippy::unwrap_used)]
+        let value = maybe_value.unwrap();
+
+        Asserter { value }
+    }
+
+    fn group_count(self) -> Asserter<usize> {
+        Asserter {
+            value: self.value.groups.len(),
+        }
+    }
+
+    fn named_groups(self) -> Asserter<BTreeMap<String, String>> {
+        Asserter {
+            value: self
+                .value
+                .groups
+                .into_iter()
+                .filter_map(|group| group.name.zip(group.value))
+                .collect(),
+        }
+    }
+}
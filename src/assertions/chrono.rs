@@ -0,0 +1,419 @@
+use crate::{
+    assertions::time::Timestamp,
+    implementation::{self, Displayed},
+    private, Asserter,
+};
+use chrono::{
+    DateTime, Datelike, FixedOffset, NaiveDate, NaiveDateTime, TimeDelta, TimeZone, Utc, Weekday,
+};
+use std::{
+    fmt::{self, Debug},
+    time::Duration,
+};
+
+/// Specifies calendar assertions on [`chrono`] dates and date times. Implemented on [`Asserter`]
+///
+/// Available for all types implementing [`Datelike`] such as [`NaiveDate`], [`NaiveDateTime`] and [`DateTime`].
+/// The calendar values of a [`DateTime`] are the ones in its timezone.
+///
+/// This trait is sealed and cannot be implemented outside Smoothy.
+pub trait ChronoCalendarAssertion<Date>: private::Sealed
+where
+    Date: Datelike,
+{
+    /// Convenience function for getting the year of the date.
+    ///
+    /// # Examples
+    /// ```
+    /// # use smoothy::prelude::*;
+    /// # use chrono::NaiveDate;
+    /// #
+    /// let date = NaiveDate::from_ymd_opt(2026, 10, 19).unwrap();
+    /// assert_that(date).year().is(2026);
+    /// ```
+    #[track_caller]
+    #[must_use = "Accessing the year does not assert anything"]
+    fn year(self) -> Asserter<i32>;
+
+    /// Convenience function for getting the month of the date starting at 1.
+    ///
+    /// # Examples
+    /// ```
+    /// # use smoothy::prelude::*;
+    /// # use chrono::NaiveDate;
+    /// #
+    /// let date = NaiveDate::from_ymd_opt(2026, 10, 19).unwrap();
+    /// assert_that(date).month().is(10);
+    /// ```
+    #[track_caller]
+    #[must_use = "Accessing the month does not assert anything"]
+    fn month(self) -> Asserter<u32>;
+
+    /// Convenience function for getting the day of the month of the date starting at 1.
+    ///
+    /// # Examples
+    /// ```
+    /// # use smoothy::prelude::*;
+    /// # use chrono::NaiveDate;
+    /// #
+    /// let date = NaiveDate::from_ymd_opt(2026, 10, 19).unwrap();
+    /// assert_that(date).day().is(19);
+    /// ```
+    #[track_caller]
+    #[must_use = "Accessing the day does not assert anything"]
+    fn day(self) -> Asserter<u32>;
+
+    /// Convenience function for getting the day of the week of the date.
+    ///
+    /// # Examples
+    /// ```
+    /// # use smoothy::prelude::*;
+    /// # use chrono::{NaiveDate, Weekday};
+    /// #
+    /// let date = NaiveDate::from_ymd_opt(2026, 10, 19).unwrap();
+    /// assert_that(date).weekday().is(Weekday::Mon);
+    /// ```
+    #[track_caller]
+    #[must_use = "Accessing the weekday does not assert anything"]
+    fn weekday(self) -> Asserter<Weekday>;
+
+    /// Asserts that the date falls on the same calendar day as the expected one.
+    ///
+    /// # Examples
+    /// ```
+    /// # use smoothy::prelude::*;
+    /// # use chrono::{NaiveDate, TimeZone, Utc};
+    /// #
+    /// let date_time = Utc.with_ymd_and_hms(2026, 10, 19, 13, 37, 0).unwrap();
+    /// assert_that(date_time).is_same_day_as(NaiveDate::from_ymd_opt(2026, 10, 19).unwrap());
+    /// ```
+    ///
+    /// ```should_panic
+    /// # use smoothy::prelude::*;
+    /// # use chrono::NaiveDate;
+    /// #
+    /// let date = NaiveDate::from_ymd_opt(2026, 10, 19).unwrap();
+    /// assert_that(date).is_same_day_as(NaiveDate::from_ymd_opt(2026, 10, 20).unwrap());
+    /// ```
+    ///
+    /// # Panics
+    /// When the year, month or day differ
+    #[track_caller]
+    #[allow(clippy::wrong_self_convention)]
+    fn is_same_day_as(self, expected: impl Datelike + Debug) -> Asserter<Date>
+    where
+        Date: Debug;
+}
+
+impl<Date> ChronoCalendarAssertion<Date> for Asserter<Date>
+where
+    Date: Datelike,
+{
+    fn year(self) -> Asserter<i32> {
+        Asserter {
+            value: self.value.year(),
+        }
+    }
+
+    fn month(self) -> Asserter<u32> {
+        Asserter {
+            value: self.value.month(),
+        }
+    }
+
+    fn day(self) -> Asserter<u32> {
+        Asserter {
+            value: self.value.day(),
+        }
+    }
+
+    fn weekday(self) -> Asserter<Weekday> {
+        Asserter {
+            value: self.value.weekday(),
+        }
+    }
+
+    fn is_same_day_as(self, expected: impl Datelike + Debug) -> Self
+    where
+        Date: Debug,
+    {
+        let is_same_day = self.value.year() == expected.year()
+            && self.value.month() == expected.month()
+            && self.value.day() == expected.day();
+
+        implementation::assert(
+            is_same_day,
+            &self.value,
+            "to be on the same day as",
+            expected,
+        );
+
+        self
+    }
+}
+
+/// Specifies assertions on [`chrono`] date times with a timezone. Implemented on [`Asserter`]
+///
+/// This trait is sealed and cannot be implemented outside Smoothy.
+pub trait ChronoDateTimeAssertion<Tz>: private::Sealed
+where
+    Tz: TimeZone,
+{
+    /// Asserts that the date time describes the same instant as the expected one regardless of their timezones.
+    ///
+    /// # Examples
+    /// ```
+    /// # use smoothy::prelude::*;
+    /// # use chrono::{FixedOffset, TimeZone, Utc};
+    /// #
+    /// let utc = Utc.with_ymd_and_hms(2026, 10, 19, 12, 0, 0).unwrap();
+    /// let cest = FixedOffset::east_opt(2 * 3600).unwrap();
+    ///
+    /// assert_that(utc).is_same_instant_as(utc.with_timezone(&cest));
+    /// ```
+    ///
+    /// ```should_panic
+    /// # use smoothy::prelude::*;
+    /// # use chrono::{FixedOffset, TimeZone, Utc};
+    /// #
+    /// let utc = Utc.with_ymd_and_hms(2026, 10, 19, 12, 0, 0).unwrap();
+    /// let cest = FixedOffset::east_opt(2 * 3600).unwrap();
+    ///
+    /// assert_that(utc).is_same_instant_as(cest.with_ymd_and_hms(2026, 10, 19, 12, 0, 0).unwrap());
+    /// ```
+    ///
+    /// # Panics
+    /// When the date times describe different instants
+    #[track_caller]
+    #[allow(clippy::wrong_self_convention)]
+    fn is_same_instant_as<OtherTz>(self, expected: DateTime<OtherTz>) -> Asserter<DateTime<Tz>>
+    where
+        OtherTz: TimeZone;
+}
+
+impl<Tz> ChronoDateTimeAssertion<Tz> for Asserter<DateTime<Tz>>
+where
+    Tz: TimeZone,
+{
+    fn is_same_instant_as<OtherTz>(self, expected: DateTime<OtherTz>) -> Self
+    where
+        OtherTz: TimeZone,
+    {
+        let difference = self
+            .value
+            .naive_utc()
+            .signed_duration_since(expected.naive_utc());
+
+        implementation::assert_with_additional_info(
+            difference == TimeDelta::zero(),
+            &self.value,
+            "to be the same instant as",
+            &expected,
+            "but differed by",
+            magnitude(difference),
+        );
+
+        self
+    }
+}
+
+/// Specifies assertions parsing string-likes into [`chrono`] types. Implemented on [`Asserter`]
+///
+/// This trait is sealed and cannot be implemented outside Smoothy.
+pub trait ChronoParseAssertion<StringLike>: private::Sealed
+where
+    StringLike: AsRef<str>,
+{
+    /// Asserts that the value is a valid RFC 3339 timestamp and converts it for further assertions.
+    ///
+    /// # Examples
+    /// ```
+    /// # use smoothy::prelude::*;
+    /// # use chrono::{TimeZone, Utc};
+    /// #
+    /// assert_that("2026-10-19T14:00:00+02:00")
+    ///     .to_chrono_date_time()
+    ///     .is_same_instant_as(Utc.with_ymd_and_hms(2026, 10, 19, 12, 0, 0).unwrap());
+    /// ```
+    ///
+    /// ```should_panic
+    /// # use smoothy::prelude::*;
+    /// #
+    /// let _ = assert_that("2026-10-19 14:00").to_chrono_date_time();
+    /// ```
+    ///
+    /// # Panics
+    /// When the value is no valid RFC 3339 timestamp
+    #[track_caller]
+    #[allow(clippy::wrong_self_convention)]
+    #[must_use = "Transforming the asserted value only asserts that it can be parsed"]
+    fn to_chrono_date_time(self) -> Asserter<DateTime<FixedOffset>>;
+
+    /// Asserts that the value is a date in the given format and converts it for further assertions.
+    ///
+    /// For the syntax of the format see [`chrono::format::strftime`].
+    ///
+    /// # Examples
+    /// ```
+    /// # use smoothy::prelude::*;
+    /// #
+    /// assert_that("19.10.2026")
+    ///     .to_chrono_naive_date("%d.%m.%Y")
+    ///     .year()
+    ///     .is(2026);
+    /// ```
+    ///
+    /// # Panics
+    /// When the value cannot be parsed with the format
+    #[track_caller]
+    #[allow(clippy::wrong_self_convention)]
+    #[must_use = "Transforming the asserted value only asserts that it can be parsed"]
+    fn to_chrono_naive_date(self, format: &str) -> Asserter<NaiveDate>;
+
+    /// Asserts that the value is a date time in the given format and converts it for further assertions.
+    ///
+    /// For the syntax of the format see [`chrono::format::strftime`].
+    ///
+    /// # Examples
+    /// ```
+    /// # use smoothy::prelude::*;
+    /// # use chrono::NaiveDate;
+    /// #
+    /// assert_that("2026-10-19 13:37")
+    ///     .to_chrono_naive_date_time("%Y-%m-%d %H:%M")
+    ///     .is_same_day_as(NaiveDate::from_ymd_opt(2026, 10, 19).unwrap());
+    /// ```
+    ///
+    /// # Panics
+    /// When the value cannot be parsed with the format
+    #[track_caller]
+    #[allow(clippy::wrong_self_convention)]
+    #[must_use = "Transforming the asserted value only asserts that it can be parsed"]
+    fn to_chrono_naive_date_time(self, format: &str) -> Asserter<NaiveDateTime>;
+}
+
+impl<StringLike> ChronoParseAssertion<StringLike> for Asserter<StringLike>
+where
+    StringLike: AsRef<str>,
+{
+    fn to_chrono_date_time(self) -> Asserter<DateTime<FixedOffset>> {
+        let actual = self.value.as_ref();
+
+        parsed(
+            actual,
+            DateTime::parse_from_rfc3339(actual),
+            "to be a RFC 3339 timestamp",
+        )
+    }
+
+    fn to_chrono_naive_date(self, format: &str) -> Asserter<NaiveDate> {
+        let actual = self.value.as_ref();
+
+        parsed(
+            actual,
+            NaiveDate::parse_from_str(actual, format),
+            &format!("to be a date in the format {format:?}"),
+        )
+    }
+
+    fn to_chrono_naive_date_time(self, format: &str) -> Asserter<NaiveDateTime> {
+        let actual = self.value.as_ref();
+
+        parsed(
+            actual,
+            NaiveDateTime::parse_from_str(actual, format),
+            &format!("to be a date time in the format {format:?}"),
+        )
+    }
+}
+
+#[track_caller]
+fn parsed<Parsed>(
+    actual: &str,
+    result: chrono::ParseResult<Parsed>,
+    assertion_desc: &str,
+) -> Asserter<Parsed> {
+    if let Err(error) = &result {
+        implementation::assert_with_additional_info_no_expected(
+            false,
+            actual,
+            assertion_desc,
+            "but parsing failed with",
+            Displayed(error),
+        );
+    }
+
+    #[allow(clippy::unwrap_used)]
+    let value = result.unwrap();
+
+    Asserter { value }
+}
+
+/// The absolute value of the time delta
+fn magnitude(delta: TimeDelta) -> Duration {
+    delta.abs().to_std().unwrap_or(Duration::MAX)
+}
+
+/// Time since the point in time or, if it lies in the future, the time until it is reached as error
+fn elapsed_since(since: TimeDelta) -> Result<Duration, Duration> {
+    if since < TimeDelta::zero() {
+        Err(magnitude(since))
+    } else {
+        Ok(magnitude(since))
+    }
+}
+
+impl<Tz: TimeZone> private::Sealed for DateTime<Tz> {}
+
+impl<Tz> Timestamp for DateTime<Tz>
+where
+    Tz: TimeZone,
+{
+    fn elapsed(&self) -> Result<Duration, Duration> {
+        elapsed_since(
+            Utc::now()
+                .naive_utc()
+                .signed_duration_since(self.naive_utc()),
+        )
+    }
+
+    fn distance(&self, other: &Self) -> Duration {
+        magnitude(self.naive_utc().signed_duration_since(other.naive_utc()))
+    }
+
+    fn fmt_timestamp(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{self:?}")
+    }
+}
+
+impl private::Sealed for NaiveDateTime {}
+
+impl Timestamp for NaiveDateTime {
+    fn elapsed(&self) -> Result<Duration, Duration> {
+        elapsed_since(Utc::now().naive_utc().signed_duration_since(*self))
+    }
+
+    fn distance(&self, other: &Self) -> Duration {
+        magnitude(self.signed_duration_since(*other))
+    }
+
+    fn fmt_timestamp(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{self:?}")
+    }
+}
+
+impl private::Sealed for NaiveDate {}
+
+impl Timestamp for NaiveDate {
+    fn elapsed(&self) -> Result<Duration, Duration> {
+        elapsed_since(Utc::now().date_naive().signed_duration_since(*self))
+    }
+
+    fn distance(&self, other: &Self) -> Duration {
+        magnitude(self.signed_duration_since(*other))
+    }
+
+    fn fmt_timestamp(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{self:?}")
+    }
+}
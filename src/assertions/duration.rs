@@ -0,0 +1,223 @@
+use crate::{implementation, private, Asserter};
+use std::time::Duration;
+
+/// Specifies various assertions on [`Duration`]. Implemented on [`Asserter`]
+///
+/// This trait is sealed and cannot be implemented outside Smoothy.
+pub trait DurationAssertion: private::Sealed {
+    /// Asserts that the [Duration] is close to the expected one.
+    ///
+    /// The allowed deviation has to be specified with [`within`](CloseToAsserter::within).
+    ///
+    /// # Examples
+    /// ```
+    /// # use smoothy::prelude::*;
+    /// # use std::time::Duration;
+    /// #
+    /// assert_that(Duration::from_millis(210))
+    ///     .is_close_to(Duration::from_millis(200))
+    ///     .within(Duration::from_millis(20));
+    /// ```
+    ///
+    /// ```should_panic
+    /// # use smoothy::prelude::*;
+    /// # use std::time::Duration;
+    /// #
+    /// assert_that(Duration::from_millis(250))
+    ///     .is_close_to(Duration::from_millis(200))
+    ///     .within(Duration::from_millis(20));
+    /// ```
+    #[track_caller]
+    #[allow(clippy::wrong_self_convention)]
+    #[must_use = "Without specifying a tolerance with .within() this does not assert anything"]
+    fn is_close_to(self, expected: Duration) -> CloseToAsserter;
+
+    /// Asserts that the [Duration] is shorter than the expected one.
+    ///
+    /// # Examples
+    /// ```
+    /// # use smoothy::prelude::*;
+    /// # use std::time::Duration;
+    /// #
+    /// assert_that(Duration::from_millis(200)).is_shorter_than(Duration::from_secs(1));
+    /// ```
+    ///
+    /// ```should_panic
+    /// # use smoothy::prelude::*;
+    /// # use std::time::Duration;
+    /// #
+    /// assert_that(Duration::from_secs(1)).is_shorter_than(Duration::from_secs(1));
+    /// ```
+    ///
+    /// # Panics
+    /// When the [Duration] is equal to or longer than the expected one
+    #[track_caller]
+    #[allow(clippy::wrong_self_convention)]
+    fn is_shorter_than(self, expected: Duration) -> Asserter<Duration>;
+
+    /// Asserts that the [Duration] is longer than the expected one.
+    ///
+    /// # Examples
+    /// ```
+    /// # use smoothy::prelude::*;
+    /// # use std::time::Duration;
+    /// #
+    /// assert_that(Duration::from_secs(2)).is_longer_than(Duration::from_secs(1));
+    /// ```
+    ///
+    /// ```should_panic
+    /// # use smoothy::prelude::*;
+    /// # use std::time::Duration;
+    /// #
+    /// assert_that(Duration::from_millis(200)).is_longer_than(Duration::from_secs(1));
+    /// ```
+    ///
+    /// # Panics
+    /// When the [Duration] is equal to or shorter than the expected one
+    #[track_caller]
+    #[allow(clippy::wrong_self_convention)]
+    fn is_longer_than(self, expected: Duration) -> Asserter<Duration>;
+
+    /// Asserts that the [Duration] is zero.
+    ///
+    /// # Examples
+    /// ```
+    /// # use smoothy::prelude::*;
+    /// # use std::time::Duration;
+    /// #
+    /// assert_that(Duration::ZERO).is_zero();
+    /// ```
+    ///
+    /// ```should_panic
+    /// # use smoothy::prelude::*;
+    /// # use std::time::Duration;
+    /// #
+    /// assert_that(Duration::from_nanos(1)).is_zero();
+    /// ```
+    ///
+    /// # Panics
+    /// When the [Duration] is not zero
+    #[track_caller]
+    #[allow(clippy::wrong_self_convention)]
+    fn is_zero(self) -> Asserter<Duration>;
+
+    /// Convenience function for getting the total number of whole milliseconds of the [Duration].
+    ///
+    /// # Examples
+    /// ```
+    /// # use smoothy::prelude::*;
+    /// # use std::time::Duration;
+    /// #
+    /// assert_that(Duration::from_micros(1_500)).as_millis().is(1);
+    /// ```
+    #[track_caller]
+    #[allow(clippy::wrong_self_convention)]
+    #[must_use = "Transforming the asserted value does not assert anything"]
+    fn as_millis(self) -> Asserter<u128>;
+
+    /// Convenience function for getting the number of seconds of the [Duration] as [f64].
+    ///
+    /// # Examples
+    /// ```
+    /// # use smoothy::prelude::*;
+    /// # use std::time::Duration;
+    /// #
+    /// assert_that(Duration::from_millis(1_500))
+    ///     .as_secs_f64()
+    ///     .is(1.5);
+    /// ```
+    #[track_caller]
+    #[allow(clippy::wrong_self_convention)]
+    #[must_use = "Transforming the asserted value does not assert anything"]
+    fn as_secs_f64(self) -> Asserter<f64>;
+}
+
+impl DurationAssertion for Asserter<Duration> {
+    fn is_close_to(self, expected: Duration) -> CloseToAsserter {
+        CloseToAsserter {
+            value: self.value,
+            expected,
+        }
+    }
+
+    fn is_shorter_than(self, expected: Duration) -> Self {
+        implementation::assert(
+            self.value < expected,
+            self.value,
+            "to be shorter than",
+            expected,
+        );
+
+        self
+    }
+
+    fn is_longer_than(self, expected: Duration) -> Self {
+        implementation::assert(
+            self.value > expected,
+            self.value,
+            "to be longer than",
+            expected,
+        );
+
+        self
+    }
+
+    fn is_zero(self) -> Self {
+        implementation::assert_no_expected(self.value.is_zero(), self.value, "to be zero");
+
+        self
+    }
+
+    fn as_millis(self) -> Asserter<u128> {
+        Asserter {
+            value: self.value.as_millis(),
+        }
+    }
+
+    fn as_secs_f64(self) -> Asserter<f64> {
+        Asserter {
+            value: self.value.as_secs_f64(),
+        }
+    }
+}
+
+/// Enables asserting that a [Duration] is close to an expected one
+pub struct CloseToAsserter {
+    value: Duration,
+    expected: Duration,
+}
+
+impl CloseToAsserter {
+    /// Asserts that the [Duration] deviates at most by the tolerance from the expected one.
+    ///
+    /// # Examples
+    /// ```
+    /// # use smoothy::prelude::*;
+    /// # use std::time::Duration;
+    /// #
+    /// let asserter: Asserter<Duration> = assert_that(Duration::from_millis(190))
+    ///     .is_close_to(Duration::from_millis(200))
+    ///     .within(Duration::from_millis(20));
+    /// // further assertions
+    /// asserter.is_shorter_than(Duration::from_millis(200));
+    /// ```
+    ///
+    /// # Panics
+    /// When the [Duration] deviates by more than the tolerance
+    #[track_caller]
+    #[allow(clippy::must_use_candidate)]
+    pub fn within(self, tolerance: Duration) -> Asserter<Duration> {
+        let deviation = self.value.abs_diff(self.expected);
+
+        implementation::assert_with_additional_info(
+            deviation <= tolerance,
+            self.value,
+            &format!("to be within {tolerance:?} of"),
+            self.expected,
+            "but differed by",
+            deviation,
+        );
+
+        Asserter { value: self.value }
+    }
+}
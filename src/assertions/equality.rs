@@ -1,4 +1,4 @@
-use crate::{implementation, private, Asserter};
+use crate::{assertions::string, implementation, private, Asserter};
 use std::fmt::Debug;
 
 /// Specifies various equality assertions. Implemented on [`Asserter`]
@@ -14,6 +14,11 @@ where
     /// This is done by transforming the expected-value to an instance of `AssertedType` by using the [Into]-trait
     /// and then comparing both values with [`PartialEq`]
     ///
+    /// Use [`equals_bytes`](crate::BytesAssertion::equals_bytes) to compare bytes like [`Vec<u8>`] or `&[u8]`
+    /// with a hexdump of the differing bytes in the failure instead of a list of decimal numbers.
+    ///
+    /// Strings which are similar but not equal are shown with the differing characters highlighted.
+    ///
     /// # Examples
     /// ```
     /// use smoothy::prelude::*;
@@ -134,6 +139,10 @@ where
     {
         let transformed_expected: AssertedType = expected.into();
 
+        if self.value != transformed_expected {
+            string::assert_equals_with_closest_match(&self.value, &transformed_expected);
+        }
+
         implementation::assert_equals(&self.value, transformed_expected);
 
         self
@@ -0,0 +1,1205 @@
+use crate::{
+    implementation::{self, Escaped},
+    private, Asserter,
+};
+use std::{
+    fmt::{self, Debug},
+    iter::Peekable,
+    str::CharIndices,
+};
+
+/// Specifies assertions on the format of string-likes. Implemented on [`Asserter`]
+///
+/// On failure the offending character and its byte offset are reported.
+///
+/// This trait is sealed and cannot be implemented outside Smoothy.
+pub trait StringFormatAssertion<StringLike>: private::Sealed
+where
+    StringLike: AsRef<str>,
+{
+    /// Asserts that the value is a UUID in its hyphenated form like `67e55044-10b1-426f-9247-bb680e5fe0c8`
+    ///
+    /// Upper- and lowercase hex digits are accepted.
+    ///
+    /// # Examples
+    /// ```
+    /// # use smoothy::prelude::*;
+    /// #
+    /// assert_that("67e55044-10b1-426f-9247-bb680e5fe0c8").is_uuid();
+    /// ```
+    ///
+    /// ```should_panic
+    /// # use smoothy::prelude::*;
+    /// #
+    /// assert_that("67e55044-10b1-426f-9247-bb680e5fe0cg").is_uuid();
+    /// ```
+    ///
+    /// # Panics
+    /// When the value is no hyphenated UUID
+    #[track_caller]
+    #[allow(clippy::wrong_self_convention)]
+    fn is_uuid(self) -> Asserter<StringLike>;
+
+    /// Asserts that the value is an email address
+    ///
+    /// The address is validated like by `<input type="email">` in HTML, i.e. the local part may not be quoted
+    /// and the domain has to consist of valid host name labels. In addition the local part may neither start
+    /// nor end with a dot nor contain consecutive dots.
+    ///
+    /// # Examples
+    /// ```
+    /// # use smoothy::prelude::*;
+    /// #
+    /// assert_that("jane.doe+test@example.com").is_email();
+    /// ```
+    ///
+    /// ```should_panic
+    /// # use smoothy::prelude::*;
+    /// #
+    /// assert_that("jane doe@example.com").is_email();
+    /// ```
+    ///
+    /// # Panics
+    /// When the value is no email address
+    #[track_caller]
+    #[allow(clippy::wrong_self_convention)]
+    fn is_email(self) -> Asserter<StringLike>;
+
+    /// Asserts that the value is an absolute URL with a scheme and a host like `https://example.com/path?query`
+    ///
+    /// Whitespace and control characters are not allowed anywhere.
+    ///
+    /// # Examples
+    /// ```
+    /// # use smoothy::prelude::*;
+    /// #
+    /// assert_that("https://example.com/search?q=smoothy").is_url();
+    /// ```
+    ///
+    /// ```should_panic
+    /// # use smoothy::prelude::*;
+    /// #
+    /// assert_that("example.com/search").is_url();
+    /// ```
+    ///
+    /// # Panics
+    /// When the value is no absolute URL
+    #[track_caller]
+    #[allow(clippy::wrong_self_convention)]
+    fn is_url(self) -> Asserter<StringLike>;
+
+    /// Asserts that the value is an IPv4 address in dotted decimal notation
+    ///
+    /// Octets with leading zeros are rejected like by [`Ipv4Addr`](std::net::Ipv4Addr).
+    ///
+    /// # Examples
+    /// ```
+    /// # use smoothy::prelude::*;
+    /// #
+    /// assert_that("192.168.0.1").is_ipv4();
+    /// ```
+    ///
+    /// ```should_panic
+    /// # use smoothy::prelude::*;
+    /// #
+    /// assert_that("192.168.0.256").is_ipv4();
+    /// ```
+    ///
+    /// # Panics
+    /// When the value is no IPv4 address
+    #[track_caller]
+    #[allow(clippy::wrong_self_convention)]
+    fn is_ipv4(self) -> Asserter<StringLike>;
+
+    /// Asserts that the value is an IPv6 address
+    ///
+    /// Compressed groups (`::`) and embedded IPv4 addresses (`::ffff:192.168.0.1`) are supported.
+    ///
+    /// # Examples
+    /// ```
+    /// # use smoothy::prelude::*;
+    /// #
+    /// assert_that("2001:db8::8a2e:370:7334").is_ipv6();
+    /// ```
+    ///
+    /// ```should_panic
+    /// # use smoothy::prelude::*;
+    /// #
+    /// assert_that("2001:db8::8a2e::7334").is_ipv6();
+    /// ```
+    ///
+    /// # Panics
+    /// When the value is no IPv6 address
+    #[track_caller]
+    #[allow(clippy::wrong_self_convention)]
+    fn is_ipv6(self) -> Asserter<StringLike>;
+
+    /// Asserts that the value is a RFC 3339 timestamp like `2026-10-19T13:37:00.123+02:00`
+    ///
+    /// The ranges of all fields are validated including the number of days in the month.
+    ///
+    /// # Examples
+    /// ```
+    /// # use smoothy::prelude::*;
+    /// #
+    /// assert_that("2026-10-19T13:37:00Z").is_rfc3339_timestamp();
+    /// ```
+    ///
+    /// ```should_panic
+    /// # use smoothy::prelude::*;
+    /// #
+    /// assert_that("2026-02-30T13:37:00Z").is_rfc3339_timestamp();
+    /// ```
+    ///
+    /// # Panics
+    /// When the value is no RFC 3339 timestamp
+    #[track_caller]
+    #[allow(clippy::wrong_self_convention)]
+    fn is_rfc3339_timestamp(self) -> Asserter<StringLike>;
+
+    /// Asserts that the value is a [semantic version](https://semver.org) like `1.2.3-rc.1+build.5`
+    ///
+    /// # Examples
+    /// ```
+    /// # use smoothy::prelude::*;
+    /// #
+    /// assert_that("1.2.3-rc.1+build.5").is_semver();
+    /// ```
+    ///
+    /// ```should_panic
+    /// # use smoothy::prelude::*;
+    /// #
+    /// assert_that("1.02.3").is_semver();
+    /// ```
+    ///
+    /// # Panics
+    /// When the value is no semantic version
+    #[track_caller]
+    #[allow(clippy::wrong_self_convention)]
+    fn is_semver(self) -> Asserter<StringLike>;
+
+    /// Asserts that the value is not empty and consists of hex digits only
+    ///
+    /// # Examples
+    /// ```
+    /// # use smoothy::prelude::*;
+    /// #
+    /// assert_that("deadBEEF42").is_hex();
+    /// ```
+    ///
+    /// ```should_panic
+    /// # use smoothy::prelude::*;
+    /// #
+    /// assert_that("0xdeadbeef").is_hex();
+    /// ```
+    ///
+    /// # Panics
+    /// When the value is empty or contains a character that is no hex digit
+    #[track_caller]
+    #[allow(clippy::wrong_self_convention)]
+    fn is_hex(self) -> Asserter<StringLike>;
+
+    /// Asserts that the value is not empty and encoded with the standard base64 alphabet including padding
+    ///
+    /// # Examples
+    /// ```
+    /// # use smoothy::prelude::*;
+    /// #
+    /// assert_that("SGVsbG8gV29ybGQ=").is_base64();
+    /// ```
+    ///
+    /// ```should_panic
+    /// # use smoothy::prelude::*;
+    /// #
+    /// assert_that("SGVsbG8gV29ybGQ").is_base64();
+    /// ```
+    ///
+    /// # Panics
+    /// When the value is empty, contains characters outside the alphabet or is not padded correctly
+    #[track_caller]
+    #[allow(clippy::wrong_self_convention)]
+    fn is_base64(self) -> Asserter<StringLike>;
+
+    /// Decodes the hex string into its bytes for further assertions. Both upper- and lowercase digits are accepted.
+    ///
+    /// # Examples
+    /// ```
+    /// # use smoothy::prelude::*;
+    /// #
+    /// assert_that("CAFEbabe")
+    ///     .hex_decoded()
+    ///     .equals_bytes([0xCA, 0xFE, 0xBA, 0xBE]);
+    /// ```
+    ///
+    /// ```should_panic
+    /// # use smoothy::prelude::*;
+    /// #
+    /// let _bytes = assert_that("cafe0").hex_decoded();
+    /// ```
+    ///
+    /// # Panics
+    /// When the value is empty, contains a character that is no hex digit or has an odd number of digits
+    #[track_caller]
+    #[must_use = "Transforming the asserted value only asserts that it is hex encoded"]
+    fn hex_decoded(self) -> Asserter<Vec<u8>>;
+
+    /// Decodes the value encoded with the standard base64 alphabet including padding into its bytes for further assertions
+    ///
+    /// # Examples
+    /// ```
+    /// # use smoothy::prelude::*;
+    /// #
+    /// assert_that("SGVsbG8gV29ybGQ=")
+    ///     .base64_decoded()
+    ///     .is_valid_utf8()
+    ///     .equals("Hello World");
+    /// ```
+    ///
+    /// ```should_panic
+    /// # use smoothy::prelude::*;
+    /// #
+    /// let _bytes = assert_that("SGVsbG8g-29ybGQ=").base64_decoded();
+    /// ```
+    ///
+    /// # Panics
+    /// When the value is empty, contains characters outside the alphabet or is not padded correctly
+    #[track_caller]
+    #[must_use = "Transforming the asserted value only asserts that it is base64 encoded"]
+    fn base64_decoded(self) -> Asserter<Vec<u8>>;
+
+    /// Decodes the value encoded with the URL-safe base64 alphabet into its bytes for further assertions
+    ///
+    /// The alphabet uses `-` and `_` instead of `+` and `/`. Padding is optional, as in JSON Web Tokens.
+    ///
+    /// # Examples
+    /// ```
+    /// # use smoothy::prelude::*;
+    /// #
+    /// assert_that("eyJhbGciOiJIUzI1NiJ9")
+    ///     .base64_url_decoded()
+    ///     .is_valid_utf8()
+    ///     .equals(r#"{"alg":"HS256"}"#);
+    /// ```
+    ///
+    /// ```should_panic
+    /// # use smoothy::prelude::*;
+    /// #
+    /// let _bytes = assert_that("a+/9").base64_url_decoded();
+    /// ```
+    ///
+    /// # Panics
+    /// When the value is empty, contains characters outside the alphabet or has an invalid length
+    #[track_caller]
+    #[must_use = "Transforming the asserted value only asserts that it is base64url encoded"]
+    fn base64_url_decoded(self) -> Asserter<Vec<u8>>;
+
+    /// Asserts that the value is valid JSON
+    ///
+    /// # Examples
+    /// ```
+    /// # use smoothy::prelude::*;
+    /// #
+    /// assert_that(r#"{"id": 42, "tags": []}"#).is_json();
+    /// ```
+    ///
+    /// ```should_panic
+    /// # use smoothy::prelude::*;
+    /// #
+    /// assert_that(r#"{"id": 42,}"#).is_json();
+    /// ```
+    ///
+    /// # Panics
+    /// When the value is no valid JSON
+    #[cfg(feature = "json")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "json")))]
+    #[track_caller]
+    #[allow(clippy::wrong_self_convention)]
+    fn is_json(self) -> Asserter<StringLike>;
+
+    /// Asserts that the value is not empty and consists of ASCII characters only
+    ///
+    /// # Examples
+    /// ```
+    /// # use smoothy::prelude::*;
+    /// #
+    /// assert_that("Hello World!").is_ascii();
+    /// ```
+    ///
+    /// ```should_panic
+    /// # use smoothy::prelude::*;
+    /// #
+    /// assert_that("Grüße").is_ascii();
+    /// ```
+    ///
+    /// # Panics
+    /// When the value is empty or contains a character that is not ASCII
+    #[track_caller]
+    #[allow(clippy::wrong_self_convention)]
+    fn is_ascii(self) -> Asserter<StringLike>;
+
+    /// Asserts that the value is not empty and consists of alphanumeric characters only
+    ///
+    /// Characters are checked with [`char::is_alphanumeric`], so letters and digits of all scripts are accepted.
+    ///
+    /// # Examples
+    /// ```
+    /// # use smoothy::prelude::*;
+    /// #
+    /// assert_that("Grüße42").is_alphanumeric();
+    /// ```
+    ///
+    /// ```should_panic
+    /// # use smoothy::prelude::*;
+    /// #
+    /// assert_that("Hello World").is_alphanumeric();
+    /// ```
+    ///
+    /// # Panics
+    /// When the value is empty or contains a character that is not alphanumeric
+    #[track_caller]
+    #[allow(clippy::wrong_self_convention)]
+    fn is_alphanumeric(self) -> Asserter<StringLike>;
+
+    /// Asserts that the value contains an uppercase character but no lowercase characters
+    ///
+    /// Characters without case like digits or punctuation are accepted besides the uppercase ones.
+    ///
+    /// # Examples
+    /// ```
+    /// # use smoothy::prelude::*;
+    /// #
+    /// assert_that("HTTP/2 OK").is_uppercase();
+    /// ```
+    ///
+    /// ```should_panic
+    /// # use smoothy::prelude::*;
+    /// #
+    /// assert_that("HTTP/2 Ok").is_uppercase();
+    /// ```
+    ///
+    /// # Panics
+    /// When the value contains a lowercase character or no uppercase character
+    #[track_caller]
+    #[allow(clippy::wrong_self_convention)]
+    fn is_uppercase(self) -> Asserter<StringLike>;
+
+    /// Asserts that the value contains a lowercase character but no uppercase characters
+    ///
+    /// Characters without case like digits or punctuation are accepted besides the lowercase ones.
+    ///
+    /// # Examples
+    /// ```
+    /// # use smoothy::prelude::*;
+    /// #
+    /// assert_that("user-42@example.com").is_lowercase();
+    /// ```
+    ///
+    /// ```should_panic
+    /// # use smoothy::prelude::*;
+    /// #
+    /// assert_that("User-42@example.com").is_lowercase();
+    /// ```
+    ///
+    /// # Panics
+    /// When the value contains an uppercase character or no lowercase character
+    #[track_caller]
+    #[allow(clippy::wrong_self_convention)]
+    fn is_lowercase(self) -> Asserter<StringLike>;
+}
+
+impl<StringLike> StringFormatAssertion<StringLike> for Asserter<StringLike>
+where
+    StringLike: AsRef<str>,
+{
+    fn is_uuid(self) -> Self {
+        assert_format(self.value.as_ref(), "a UUID", uuid);
+
+        self
+    }
+
+    fn is_email(self) -> Self {
+        assert_format(self.value.as_ref(), "an email address", email);
+
+        self
+    }
+
+    fn is_url(self) -> Self {
+        assert_format(self.value.as_ref(), "a URL", url);
+
+        self
+    }
+
+    fn is_ipv4(self) -> Self {
+        assert_format(self.value.as_ref(), "an IPv4 address", |scanner| {
+            ipv4(scanner)?;
+            scanner.end()
+        });
+
+        self
+    }
+
+    fn is_ipv6(self) -> Self {
+        assert_format(self.value.as_ref(), "an IPv6 address", ipv6);
+
+        self
+    }
+
+    fn is_rfc3339_timestamp(self) -> Self {
+        assert_format(self.value.as_ref(), "a RFC 3339 timestamp", rfc3339);
+
+        self
+    }
+
+    fn is_semver(self) -> Self {
+        assert_format(self.value.as_ref(), "a semantic version", semver);
+
+        self
+    }
+
+    fn is_hex(self) -> Self {
+        assert_format(self.value.as_ref(), "a hex string", hex);
+
+        self
+    }
+
+    fn is_base64(self) -> Self {
+        assert_format(self.value.as_ref(), "base64 encoded", base64);
+
+        self
+    }
+
+    fn hex_decoded(self) -> Asserter<Vec<u8>> {
+        Asserter {
+            value: assert_format(self.value.as_ref(), "a hex string", decode_hex),
+        }
+    }
+
+    fn base64_decoded(self) -> Asserter<Vec<u8>> {
+        Asserter {
+            value: assert_format(self.value.as_ref(), "base64 encoded", |scanner| {
+                decode_base64(scanner, Base64::Standard)
+            }),
+        }
+    }
+
+    fn base64_url_decoded(self) -> Asserter<Vec<u8>> {
+        Asserter {
+            value: assert_format(self.value.as_ref(), "base64url encoded", |scanner| {
+                decode_base64(scanner, Base64::UrlSafe)
+            }),
+        }
+    }
+
+    #[cfg(feature = "json")]
+    fn is_json(self) -> Self {
+        let actual = self.value.as_ref();
+
+        if let Err(error) = serde_json::from_str::<serde_json::Value>(actual) {
+            implementation::assert_with_additional_info_no_expected(
+                false,
+                Escaped(actual),
+                "to be JSON",
+                "but parsing failed with",
+                implementation::Displayed(error),
+            );
+        }
+
+        self
+    }
+
+    fn is_ascii(self) -> Self {
+        assert_format(self.value.as_ref(), "ASCII", |scanner| {
+            scanner.all_non_empty(|character| character.is_ascii(), "an ASCII character")
+        });
+
+        self
+    }
+
+    fn is_alphanumeric(self) -> Self {
+        assert_format(self.value.as_ref(), "alphanumeric", |scanner| {
+            scanner.all_non_empty(char::is_alphanumeric, "an alphanumeric character")
+        });
+
+        self
+    }
+
+    fn is_uppercase(self) -> Self {
+        assert_format(self.value.as_ref(), "uppercase", |scanner| {
+            cased(
+                scanner,
+                char::is_uppercase,
+                char::is_lowercase,
+                "an uppercase or uncased character",
+                "an uppercase character",
+            )
+        });
+
+        self
+    }
+
+    fn is_lowercase(self) -> Self {
+        assert_format(self.value.as_ref(), "lowercase", |scanner| {
+            cased(
+                scanner,
+                char::is_lowercase,
+                char::is_uppercase,
+                "a lowercase or uncased character",
+                "a lowercase character",
+            )
+        });
+
+        self
+    }
+}
+
+/// Fails if the value does not conform to the format and returns what the validation produced otherwise
+#[track_caller]
+fn assert_format<Validated: Default>(
+    actual: &str,
+    format: &str,
+    validate: impl FnOnce(&mut Scanner<'_>) -> Result<Validated, Invalid>,
+) -> Validated {
+    match validate(&mut Scanner::new(actual)) {
+        Ok(validated) => validated,
+        Err(invalid) => {
+            implementation::assert_with_additional_info_no_expected(
+                false,
+                Escaped(actual),
+                &format!("to be {format}"),
+                "but found",
+                invalid,
+            );
+            Validated::default()
+        }
+    }
+}
+
+/// Why and where a value does not conform to a format
+enum Invalid {
+    /// A character or the end of the value where something else was expected
+    Unexpected {
+        /// [`None`] for the end of the value
+        found: Option<char>,
+        offset: usize,
+        expected: &'static str,
+    },
+    /// A syntactically valid part of the value with an invalid value like the 13th month
+    Value { offset: usize, message: String },
+}
+
+impl Debug for Invalid {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Unexpected {
+                found: Some(character),
+                offset,
+                expected,
+            } => {
+                write!(
+                    f,
+                    "{character:?} at byte {offset} where {expected} is expected"
+                )
+            }
+            Self::Unexpected {
+                found: None,
+                offset,
+                expected,
+            } => {
+                write!(
+                    f,
+                    "the end of the value at byte {offset} where {expected} is expected"
+                )
+            }
+            Self::Value { offset, message } => write!(f, "{message} at byte {offset}"),
+        }
+    }
+}
+
+/// Reads a value character by character keeping track of the byte offset
+#[derive(Clone)]
+struct Scanner<'a> {
+    value: &'a str,
+    characters: Peekable<CharIndices<'a>>,
+}
+
+impl<'a> Scanner<'a> {
+    fn new(value: &'a str) -> Self {
+        Self {
+            value,
+            characters: value.char_indices().peekable(),
+        }
+    }
+
+    fn offset(&mut self) -> usize {
+        self.characters
+            .peek()
+            .map_or(self.value.len(), |(offset, _character)| *offset)
+    }
+
+    fn peek(&mut self) -> Option<char> {
+        self.characters
+            .peek()
+            .map(|(_offset, character)| *character)
+    }
+
+    fn is_at_end(&mut self) -> bool {
+        self.characters.peek().is_none()
+    }
+
+    /// Fails at the current character
+    fn unexpected(&mut self, expected: &'static str) -> Invalid {
+        Invalid::Unexpected {
+            found: self.peek(),
+            offset: self.offset(),
+            expected,
+        }
+    }
+
+    /// Consumes the next character if it is the expected one
+    fn eat(&mut self, expected: char) -> bool {
+        self.characters
+            .next_if(|(_offset, character)| *character == expected)
+            .is_some()
+    }
+
+    /// Consumes the next character if it matches or fails otherwise
+    fn expect(
+        &mut self,
+        predicate: impl FnOnce(char) -> bool,
+        expected: &'static str,
+    ) -> Result<char, Invalid> {
+        match self
+            .characters
+            .next_if(|(_offset, character)| predicate(*character))
+        {
+            Some((_offset, character)) => Ok(character),
+            None => Err(self.unexpected(expected)),
+        }
+    }
+
+    /// Consumes characters as long as they match
+    fn take_while(&mut self, predicate: impl Fn(char) -> bool) -> &'a str {
+        let start = self.offset();
+        while self
+            .characters
+            .next_if(|(_offset, character)| predicate(*character))
+            .is_some()
+        {}
+
+        let end = self.offset();
+        self.value.get(start..end).unwrap_or_default()
+    }
+
+    /// Consumes all remaining characters which all have to match
+    fn all(
+        &mut self,
+        predicate: impl Fn(char) -> bool,
+        expected: &'static str,
+    ) -> Result<(), Invalid> {
+        self.take_while(predicate);
+        self.end_with(expected)
+    }
+
+    /// Consumes all remaining characters of which there has to be at least one and which all have to match
+    fn all_non_empty(
+        &mut self,
+        predicate: impl Fn(char) -> bool,
+        expected: &'static str,
+    ) -> Result<(), Invalid> {
+        self.expect(&predicate, expected)?;
+        self.all(predicate, expected)
+    }
+
+    /// Fails if there are characters left
+    fn end(&mut self) -> Result<(), Invalid> {
+        self.end_with("the end of the value")
+    }
+
+    /// Fails with the description of what was expected instead if there are characters left
+    fn end_with(&mut self, expected: &'static str) -> Result<(), Invalid> {
+        if self.is_at_end() {
+            Ok(())
+        } else {
+            Err(self.unexpected(expected))
+        }
+    }
+
+    /// Consumes exactly the number of decimal digits and returns their value
+    fn digits(&mut self, count: usize) -> Result<u32, Invalid> {
+        (0..count).try_fold(0, |number, _index| {
+            let digit = self.expect(|character| character.is_ascii_digit(), "a decimal digit")?;
+            Ok(number * 10 + digit.to_digit(10).unwrap_or_default())
+        })
+    }
+
+    /// Consumes exactly the number of decimal digits which have to lie in the range
+    fn number_in(
+        &mut self,
+        count: usize,
+        range: std::ops::RangeInclusive<u32>,
+        field: &str,
+    ) -> Result<u32, Invalid> {
+        let offset = self.offset();
+        let number = self.digits(count)?;
+
+        if range.contains(&number) {
+            Ok(number)
+        } else {
+            Err(Invalid::Value {
+                offset,
+                message: format!(
+                    "{field} {number} out of the range {}..={}",
+                    range.start(),
+                    range.end()
+                ),
+            })
+        }
+    }
+}
+
+fn uuid(scanner: &mut Scanner<'_>) -> Result<(), Invalid> {
+    for (index, length) in [8, 4, 4, 4, 12].into_iter().enumerate() {
+        if index > 0 {
+            scanner.expect(|character| character == '-', "'-'")?;
+        }
+        for _digit in 0..length {
+            scanner.expect(|character| character.is_ascii_hexdigit(), "a hex digit")?;
+        }
+    }
+
+    scanner.end()
+}
+
+/// Consumes the remaining characters which may not have the opposite case and of which at least one has the case
+fn cased(
+    scanner: &mut Scanner<'_>,
+    has_case: fn(char) -> bool,
+    has_opposite_case: fn(char) -> bool,
+    expected: &'static str,
+    expected_cased: &'static str,
+) -> Result<(), Invalid> {
+    let value = scanner.take_while(|character| !has_opposite_case(character));
+    scanner.end_with(expected)?;
+
+    if value.chars().any(has_case) {
+        Ok(())
+    } else {
+        Err(scanner.unexpected(expected_cased))
+    }
+}
+
+fn email(scanner: &mut Scanner<'_>) -> Result<(), Invalid> {
+    let offset = scanner.offset();
+    let local = scanner.take_while(|character| {
+        character.is_ascii_alphanumeric() || ".!#$%&'*+/=?^_`{|}~-".contains(character)
+    });
+
+    if local.is_empty() {
+        return Err(scanner.unexpected("a character of the local part"));
+    }
+    if local.starts_with('.') || local.ends_with('.') {
+        return Err(Invalid::Value {
+            offset,
+            message: format!("local part {local:?} starting or ending with '.'"),
+        });
+    }
+    if local.contains("..") {
+        return Err(Invalid::Value {
+            offset,
+            message: format!("local part {local:?} with consecutive '.'"),
+        });
+    }
+
+    scanner.expect(
+        |character| character == '@',
+        "a character of the local part or '@'",
+    )?;
+    host(scanner)?;
+    scanner.end()
+}
+
+/// Consumes a host name consisting of labels separated by dots
+fn host(scanner: &mut Scanner<'_>) -> Result<(), Invalid> {
+    loop {
+        let offset = scanner.offset();
+        let label =
+            scanner.take_while(|character| character.is_ascii_alphanumeric() || character == '-');
+
+        if label.is_empty() {
+            return Err(scanner.unexpected("a letter or digit"));
+        }
+        if label.starts_with('-') || label.ends_with('-') {
+            return Err(Invalid::Value {
+                offset,
+                message: format!("label {label:?} starting or ending with '-'"),
+            });
+        }
+        if label.len() > 63 {
+            return Err(Invalid::Value {
+                offset,
+                message: format!(
+                    "label with {} instead of at most 63 characters",
+                    label.len()
+                ),
+            });
+        }
+        if !scanner.eat('.') {
+            return Ok(());
+        }
+    }
+}
+
+fn url(scanner: &mut Scanner<'_>) -> Result<(), Invalid> {
+    let is_visible = |character: char| !character.is_whitespace() && !character.is_control();
+
+    scanner.expect(|character| character.is_ascii_alphabetic(), "a letter")?;
+    scanner.take_while(|character| character.is_ascii_alphanumeric() || "+-.".contains(character));
+    scanner.expect(
+        |character| character == ':',
+        "a character of the scheme or ':'",
+    )?;
+    scanner.expect(|character| character == '/', "'/'")?;
+    scanner.expect(|character| character == '/', "'/'")?;
+
+    let authority =
+        scanner.take_while(|character| is_visible(character) && !"/?#".contains(character));
+    if authority.is_empty() {
+        return Err(scanner.unexpected("a host"));
+    }
+
+    scanner.all(is_visible, "a character other than whitespace")
+}
+
+/// Consumes four decimal octets separated by dots
+fn ipv4(scanner: &mut Scanner<'_>) -> Result<(), Invalid> {
+    for index in 0..4 {
+        if index > 0 {
+            scanner.expect(|character| character == '.', "'.'")?;
+        }
+
+        let offset = scanner.offset();
+        let octet = scanner.take_while(|character| character.is_ascii_digit());
+
+        if octet.is_empty() {
+            return Err(scanner.unexpected("a decimal digit"));
+        }
+        if octet.len() > 1 && octet.starts_with('0') {
+            return Err(Invalid::Value {
+                offset,
+                message: format!("octet {octet} with a leading zero"),
+            });
+        }
+        if octet.parse::<u8>().is_err() {
+            return Err(Invalid::Value {
+                offset,
+                message: format!("octet {octet} greater than 255"),
+            });
+        }
+    }
+
+    Ok(())
+}
+
+fn ipv6(scanner: &mut Scanner<'_>) -> Result<(), Invalid> {
+    let mut groups = 0;
+    let mut compressed = scanner.clone().take_while(|character| character == ':') == "::"
+        && scanner.eat(':')
+        && scanner.eat(':');
+    let mut group_expected = !compressed;
+
+    loop {
+        if !group_expected && scanner.is_at_end() {
+            break;
+        }
+
+        let checkpoint = scanner.clone();
+        let offset = scanner.offset();
+        let group = scanner.take_while(|character| character.is_ascii_hexdigit());
+
+        // the last 32 bits can be written as an IPv4 address
+        if scanner.peek() == Some('.') {
+            *scanner = checkpoint;
+            ipv4(scanner)?;
+            groups += 2;
+            break;
+        }
+        if group.is_empty() {
+            return Err(scanner.unexpected("a hex digit"));
+        }
+        if group.len() > 4 {
+            return Err(Invalid::Value {
+                offset,
+                message: format!("group {group} with more than 4 hex digits"),
+            });
+        }
+
+        groups += 1;
+        if scanner.is_at_end() {
+            break;
+        }
+
+        scanner.expect(|character| character == ':', "a hex digit or ':'")?;
+        let separator_offset = scanner.offset();
+        group_expected = !scanner.eat(':');
+
+        if !group_expected {
+            if compressed {
+                return Err(Invalid::Value {
+                    offset: separator_offset - 1,
+                    message: String::from("second '::'"),
+                });
+            }
+            compressed = true;
+        }
+    }
+
+    scanner.end()?;
+
+    let (valid, expected) = if compressed {
+        (groups <= 7, "at most 7")
+    } else {
+        (groups == 8, "8")
+    };
+
+    if valid {
+        Ok(())
+    } else {
+        Err(Invalid::Value {
+            offset: scanner.offset(),
+            message: format!("{groups} groups instead of {expected}"),
+        })
+    }
+}
+
+fn rfc3339(scanner: &mut Scanner<'_>) -> Result<(), Invalid> {
+    let year = scanner.digits(4)?;
+    scanner.expect(|character| character == '-', "'-'")?;
+    let month = scanner.number_in(2, 1..=12, "month")?;
+    scanner.expect(|character| character == '-', "'-'")?;
+    scanner.number_in(2, 1..=days_in_month(year, month), "day")?;
+
+    scanner.expect(|character| matches!(character, 'T' | 't' | ' '), "'T'")?;
+
+    scanner.number_in(2, 0..=23, "hour")?;
+    scanner.expect(|character| character == ':', "':'")?;
+    scanner.number_in(2, 0..=59, "minute")?;
+    scanner.expect(|character| character == ':', "':'")?;
+    // 60 for leap seconds
+    scanner.number_in(2, 0..=60, "second")?;
+
+    if scanner.eat('.') {
+        scanner.expect(|character| character.is_ascii_digit(), "a decimal digit")?;
+        scanner.take_while(|character| character.is_ascii_digit());
+    }
+
+    let offset = scanner.expect(
+        |character| matches!(character, 'Z' | 'z' | '+' | '-'),
+        "'Z' or an offset like '+02:00'",
+    )?;
+    if matches!(offset, '+' | '-') {
+        scanner.number_in(2, 0..=23, "offset hour")?;
+        scanner.expect(|character| character == ':', "':'")?;
+        scanner.number_in(2, 0..=59, "offset minute")?;
+    }
+
+    scanner.end()
+}
+
+const fn days_in_month(year: u32, month: u32) -> u32 {
+    match month {
+        2 if year % 4 == 0 && (year % 100 != 0 || year % 400 == 0) => 29,
+        2 => 28,
+        4 | 6 | 9 | 11 => 30,
+        _ => 31,
+    }
+}
+
+fn semver(scanner: &mut Scanner<'_>) -> Result<(), Invalid> {
+    for (index, field) in ["major", "minor", "patch"].into_iter().enumerate() {
+        if index > 0 {
+            scanner.expect(|character| character == '.', "'.'")?;
+        }
+
+        let offset = scanner.offset();
+        let number = scanner.take_while(|character| character.is_ascii_digit());
+
+        if number.is_empty() {
+            return Err(scanner.unexpected("a decimal digit"));
+        }
+        if number.len() > 1 && number.starts_with('0') {
+            return Err(Invalid::Value {
+                offset,
+                message: format!("{field} version {number} with a leading zero"),
+            });
+        }
+    }
+
+    if scanner.eat('-') {
+        identifiers(scanner, "pre-release")?;
+    }
+    if scanner.eat('+') {
+        identifiers(scanner, "build")?;
+    }
+
+    scanner.end()
+}
+
+/// Consumes the dot-separated identifiers of the pre-release or build metadata
+fn identifiers(scanner: &mut Scanner<'_>, kind: &str) -> Result<(), Invalid> {
+    loop {
+        let offset = scanner.offset();
+        let identifier =
+            scanner.take_while(|character| character.is_ascii_alphanumeric() || character == '-');
+
+        if identifier.is_empty() {
+            return Err(scanner.unexpected("a letter, digit or '-'"));
+        }
+        // only numeric identifiers of pre-releases are compared numerically
+        if kind == "pre-release"
+            && identifier.len() > 1
+            && identifier.starts_with('0')
+            && identifier
+                .chars()
+                .all(|character| character.is_ascii_digit())
+        {
+            return Err(Invalid::Value {
+                offset,
+                message: format!("numeric {kind} identifier {identifier} with a leading zero"),
+            });
+        }
+        if !scanner.eat('.') {
+            return Ok(());
+        }
+    }
+}
+
+fn hex(scanner: &mut Scanner<'_>) -> Result<(), Invalid> {
+    scanner.expect(|character| character.is_ascii_hexdigit(), "a hex digit")?;
+    scanner.all(|character| character.is_ascii_hexdigit(), "a hex digit")
+}
+
+/// Consumes pairs of hex digits and returns the bytes they encode
+fn decode_hex(scanner: &mut Scanner<'_>) -> Result<Vec<u8>, Invalid> {
+    hex(scanner)?;
+
+    if scanner.value.len() % 2 != 0 {
+        return Err(scanner.unexpected("a second hex digit for the last byte"));
+    }
+
+    let digits = scanner
+        .value
+        .chars()
+        .filter_map(|character| character.to_digit(16))
+        .collect::<Vec<u32>>();
+
+    Ok(digits
+        .chunks(2)
+        .filter_map(|pair| u8::try_from(pair.iter().fold(0, |byte, digit| byte * 16 + digit)).ok())
+        .collect())
+}
+
+/// The alphabet of a base64 encoding
+#[derive(Clone, Copy)]
+enum Base64 {
+    /// `+` and `/` as the last two characters with mandatory padding
+    Standard,
+    /// `-` and `_` as the last two characters with optional padding
+    UrlSafe,
+}
+
+impl Base64 {
+    /// The value of the character in the alphabet
+    fn sextet(self, character: char) -> Option<u32> {
+        let (plus, slash) = match self {
+            Self::Standard => ('+', '/'),
+            Self::UrlSafe => ('-', '_'),
+        };
+
+        match character {
+            'A'..='Z' => Some(u32::from(character) - u32::from('A')),
+            'a'..='z' => Some(u32::from(character) - u32::from('a') + 26),
+            '0'..='9' => Some(u32::from(character) - u32::from('0') + 52),
+            _ if character == plus => Some(62),
+            _ if character == slash => Some(63),
+            _ => None,
+        }
+    }
+
+    const fn character(self) -> &'static str {
+        match self {
+            Self::Standard => "a base64 character",
+            Self::UrlSafe => "a base64url character",
+        }
+    }
+
+    const fn character_or_padding(self) -> &'static str {
+        match self {
+            Self::Standard => "a base64 character or '='",
+            Self::UrlSafe => "a base64url character, '=' or the end of the value",
+        }
+    }
+}
+
+fn base64(scanner: &mut Scanner<'_>) -> Result<(), Invalid> {
+    base64_with(scanner, Base64::Standard)
+}
+
+fn base64_with(scanner: &mut Scanner<'_>, alphabet: Base64) -> Result<(), Invalid> {
+    let is_base64 = |character: char| alphabet.sextet(character).is_some();
+
+    scanner.expect(is_base64, alphabet.character())?;
+    scanner.take_while(is_base64);
+
+    let padding = usize::from(scanner.eat('=')) + usize::from(scanner.eat('='));
+    scanner.end_with(match padding {
+        0 => alphabet.character_or_padding(),
+        1 => "'=' or the end of the value",
+        _ => "the end of the value",
+    })?;
+
+    let length = scanner.value.len();
+    match (alphabet, padding, length % 4) {
+        // unpadded values can end with two or three characters encoding one or two bytes
+        (_, _, 0) | (Base64::UrlSafe, 0, 2 | 3) => Ok(()),
+        (Base64::UrlSafe, 0, _) => {
+            Err(Invalid::Value {
+                offset: length,
+                message: format!(
+                    "length {length} which leaves a single character for the last byte"
+                ),
+            })
+        }
+        _ => {
+            Err(Invalid::Value {
+                offset: length,
+                message: format!("length {length} which is no multiple of 4"),
+            })
+        }
+    }
+}
+
+/// Consumes the base64 encoded value and returns the bytes it encodes
+fn decode_base64(scanner: &mut Scanner<'_>, alphabet: Base64) -> Result<Vec<u8>, Invalid> {
+    base64_with(scanner, alphabet)?;
+
+    let mut bytes = Vec::new();
+    let mut buffer = 0_u32;
+    let mut bits = 0_u32;
+
+    for sextet in scanner
+        .value
+        .chars()
+        .filter_map(|character| alphabet.sextet(character))
+    {
+        buffer = (buffer << 6) | sextet;
+        bits += 6;
+
+        if bits >= 8 {
+            bits -= 8;
+            bytes.extend(u8::try_from(buffer >> bits).ok());
+            buffer &= (1 << bits) - 1;
+        }
+    }
+
+    Ok(bytes)
+}
@@ -0,0 +1,399 @@
+use crate::{implementation, private, Asserter};
+use std::{
+    fmt::{self, Binary, Debug, UpperHex},
+    mem,
+    num::{
+        NonZeroI128, NonZeroI16, NonZeroI32, NonZeroI64, NonZeroI8, NonZeroIsize, NonZeroU128,
+        NonZeroU16, NonZeroU32, NonZeroU64, NonZeroU8, NonZeroUsize,
+    },
+};
+
+/// Specifies various assertions on integers. Implemented on [`Asserter`]
+///
+/// Available for all primitive integer types and their [`NonZero`](std::num::NonZero) counterparts.
+/// Arguments are always given as the underlying `Primitive` integer type.
+///
+/// This trait is sealed and cannot be implemented outside Smoothy.
+pub trait IntegerAssertion<Integer, Primitive>: private::Sealed {
+    /// Asserts that the integer is greater than zero.
+    ///
+    /// # Examples
+    /// ```
+    /// # use smoothy::prelude::*;
+    /// #
+    /// assert_that(42).is_positive();
+    /// ```
+    ///
+    /// ```should_panic
+    /// # use smoothy::prelude::*;
+    /// #
+    /// assert_that(-42).is_positive();
+    /// ```
+    ///
+    /// # Panics
+    /// When the integer is zero or negative
+    #[track_caller]
+    #[allow(clippy::wrong_self_convention)]
+    fn is_positive(self) -> Asserter<Integer>;
+
+    /// Asserts that the integer is less than zero.
+    ///
+    /// # Examples
+    /// ```
+    /// # use smoothy::prelude::*;
+    /// #
+    /// assert_that(-42).is_negative();
+    /// ```
+    ///
+    /// ```should_panic
+    /// # use smoothy::prelude::*;
+    /// #
+    /// assert_that(42u8).is_negative();
+    /// ```
+    ///
+    /// # Panics
+    /// When the integer is zero or positive
+    #[track_caller]
+    #[allow(clippy::wrong_self_convention)]
+    fn is_negative(self) -> Asserter<Integer>;
+
+    /// Asserts that the integer is zero.
+    ///
+    /// # Examples
+    /// ```
+    /// # use smoothy::prelude::*;
+    /// #
+    /// assert_that(0).is_zero();
+    /// ```
+    ///
+    /// ```should_panic
+    /// # use smoothy::prelude::*;
+    /// #
+    /// assert_that(1).is_zero();
+    /// ```
+    ///
+    /// # Panics
+    /// When the integer is not zero
+    #[track_caller]
+    #[allow(clippy::wrong_self_convention)]
+    fn is_zero(self) -> Asserter<Integer>;
+
+    /// Asserts that the integer is even.
+    ///
+    /// # Examples
+    /// ```
+    /// # use smoothy::prelude::*;
+    /// #
+    /// assert_that(42).is_even();
+    /// ```
+    ///
+    /// ```should_panic
+    /// # use smoothy::prelude::*;
+    /// #
+    /// assert_that(41).is_even();
+    /// ```
+    ///
+    /// # Panics
+    /// When the integer is odd
+    #[track_caller]
+    #[allow(clippy::wrong_self_convention)]
+    fn is_even(self) -> Asserter<Integer>;
+
+    /// Asserts that the integer is odd.
+    ///
+    /// # Examples
+    /// ```
+    /// # use smoothy::prelude::*;
+    /// #
+    /// assert_that(41).is_odd();
+    /// ```
+    ///
+    /// ```should_panic
+    /// # use smoothy::prelude::*;
+    /// #
+    /// assert_that(42).is_odd();
+    /// ```
+    ///
+    /// # Panics
+    /// When the integer is even
+    #[track_caller]
+    #[allow(clippy::wrong_self_convention)]
+    fn is_odd(self) -> Asserter<Integer>;
+
+    /// Asserts that the integer is divisible by the divisor without remainder.
+    ///
+    /// Only zero is considered to be divisible by zero.
+    ///
+    /// # Examples
+    /// ```
+    /// # use smoothy::prelude::*;
+    /// #
+    /// assert_that(42).is_divisible_by(7);
+    /// ```
+    ///
+    /// ```should_panic
+    /// # use smoothy::prelude::*;
+    /// #
+    /// assert_that(42).is_divisible_by(5);
+    /// ```
+    ///
+    /// # Panics
+    /// When dividing the integer by the divisor leaves a remainder
+    #[track_caller]
+    #[allow(clippy::wrong_self_convention)]
+    fn is_divisible_by(self, divisor: Primitive) -> Asserter<Integer>;
+
+    /// Asserts that the integer is a power of two.
+    ///
+    /// # Examples
+    /// ```
+    /// # use smoothy::prelude::*;
+    /// #
+    /// assert_that(64).is_power_of_two();
+    /// ```
+    ///
+    /// ```should_panic
+    /// # use smoothy::prelude::*;
+    /// #
+    /// assert_that(42).is_power_of_two();
+    /// ```
+    ///
+    /// # Panics
+    /// When the integer is not a power of two
+    #[track_caller]
+    #[allow(clippy::wrong_self_convention)]
+    fn is_power_of_two(self) -> Asserter<Integer>;
+
+    /// Asserts that the bit at the given index (starting at the least significant bit) is set.
+    ///
+    /// # Examples
+    /// ```
+    /// # use smoothy::prelude::*;
+    /// #
+    /// assert_that(0b0100u8).has_bit_set(2);
+    /// ```
+    ///
+    /// ```should_panic
+    /// # use smoothy::prelude::*;
+    /// #
+    /// assert_that(0b0100u8).has_bit_set(1);
+    /// ```
+    ///
+    /// # Panics
+    /// When the bit is not set or the index is out of range for the integer type
+    #[track_caller]
+    fn has_bit_set(self, bit: u32) -> Asserter<Integer>;
+
+    /// Asserts that the integer is a multiple of the alignment.
+    ///
+    /// # Examples
+    /// ```
+    /// # use smoothy::prelude::*;
+    /// #
+    /// assert_that(0x1000usize).is_aligned_to(0x100);
+    /// ```
+    ///
+    /// ```should_panic
+    /// # use smoothy::prelude::*;
+    /// #
+    /// assert_that(0x1004usize).is_aligned_to(0x100);
+    /// ```
+    ///
+    /// # Panics
+    /// When the alignment is not a power of two or the integer is not aligned to it
+    #[track_caller]
+    #[allow(clippy::wrong_self_convention)]
+    fn is_aligned_to(self, alignment: Primitive) -> Asserter<Integer>;
+
+    /// Convenience function for getting the number of ones in the binary representation of the integer.
+    ///
+    /// # Examples
+    /// ```
+    /// # use smoothy::prelude::*;
+    /// #
+    /// assert_that(0b1011u8).count_ones().is(3);
+    /// ```
+    #[track_caller]
+    #[must_use = "Accessing the number of ones does not assert anything"]
+    fn count_ones(self) -> Asserter<u32>;
+}
+
+/// Renders an integer together with its binary representation
+struct Bits<Integer>(Integer);
+
+impl<Integer: Debug + Binary> Debug for Bits<Integer> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let width = mem::size_of::<Integer>() * 8 + 2;
+        write!(f, "{:?} ({:#0width$b})", self.0, self.0)
+    }
+}
+
+/// Renders an integer together with its hexadecimal representation
+struct Hex<Integer>(Integer);
+
+impl<Integer: Debug + UpperHex> Debug for Hex<Integer> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let width = mem::size_of::<Integer>() * 2 + 2;
+        write!(f, "{:?} ({:#0width$X})", self.0, self.0)
+    }
+}
+
+macro_rules! impl_integer_assertion {
+    ($($asserted:ty => $primitive:ty),* $(,)?) => {
+        $(
+            impl IntegerAssertion<$asserted, $primitive> for Asserter<$asserted> {
+                fn is_positive(self) -> Self {
+                    let actual = <$primitive>::from(self.value);
+
+                    implementation::assert_no_expected(
+                        actual > <$primitive>::default(),
+                        actual,
+                        "to be positive",
+                    );
+
+                    self
+                }
+
+                fn is_negative(self) -> Self {
+                    let actual = <$primitive>::from(self.value);
+
+                    implementation::assert_no_expected(
+                        actual < <$primitive>::default(),
+                        actual,
+                        "to be negative",
+                    );
+
+                    self
+                }
+
+                fn is_zero(self) -> Self {
+                    let actual = <$primitive>::from(self.value);
+
+                    implementation::assert_no_expected(
+                        actual == <$primitive>::default(),
+                        actual,
+                        "to be zero",
+                    );
+
+                    self
+                }
+
+                fn is_even(self) -> Self {
+                    let actual = <$primitive>::from(self.value);
+
+                    implementation::assert_no_expected(
+                        actual % 2 == 0,
+                        actual,
+                        "to be even",
+                    );
+
+                    self
+                }
+
+                fn is_odd(self) -> Self {
+                    let actual = <$primitive>::from(self.value);
+
+                    implementation::assert_no_expected(
+                        actual % 2 != 0,
+                        actual,
+                        "to be odd",
+                    );
+
+                    self
+                }
+
+                fn is_divisible_by(self, divisor: $primitive) -> Self {
+                    let actual = <$primitive>::from(self.value);
+
+                    let is_divisible = if divisor == <$primitive>::default() {
+                        actual == <$primitive>::default()
+                    } else {
+                        actual.wrapping_rem(divisor) == <$primitive>::default()
+                    };
+
+                    implementation::assert(is_divisible, actual, "to be divisible by", divisor);
+
+                    self
+                }
+
+                fn is_power_of_two(self) -> Self {
+                    let actual = <$primitive>::from(self.value);
+
+                    implementation::assert_no_expected(
+                        actual > <$primitive>::default() && actual.count_ones() == 1,
+                        Bits(actual),
+                        "to be a power of two",
+                    );
+
+                    self
+                }
+
+                fn has_bit_set(self, bit: u32) -> Self {
+                    let actual = <$primitive>::from(self.value);
+
+                    let is_set = actual
+                        .checked_shr(bit)
+                        .is_some_and(|shifted| shifted & 1 == 1);
+
+                    implementation::assert(is_set, Bits(actual), "to have the bit set at index", bit);
+
+                    self
+                }
+
+                fn is_aligned_to(self, alignment: $primitive) -> Self {
+                    let actual = <$primitive>::from(self.value);
+
+                    implementation::assert_no_expected(
+                        alignment > <$primitive>::default() && alignment.count_ones() == 1,
+                        Hex(alignment),
+                        "to be a power of two to be used as alignment",
+                    );
+
+                    implementation::assert(
+                        actual.wrapping_rem(alignment) == <$primitive>::default(),
+                        Hex(actual),
+                        "to be aligned to",
+                        Hex(alignment),
+                    );
+
+                    self
+                }
+
+                fn count_ones(self) -> Asserter<u32> {
+                    let actual = <$primitive>::from(self.value);
+
+                    Asserter {
+                        value: actual.count_ones(),
+                    }
+                }
+            }
+        )*
+    };
+}
+
+impl_integer_assertion!(
+    u8 => u8,
+    u16 => u16,
+    u32 => u32,
+    u64 => u64,
+    u128 => u128,
+    usize => usize,
+    i8 => i8,
+    i16 => i16,
+    i32 => i32,
+    i64 => i64,
+    i128 => i128,
+    isize => isize,
+    NonZeroU8 => u8,
+    NonZeroU16 => u16,
+    NonZeroU32 => u32,
+    NonZeroU64 => u64,
+    NonZeroU128 => u128,
+    NonZeroUsize => usize,
+    NonZeroI8 => i8,
+    NonZeroI16 => i16,
+    NonZeroI32 => i32,
+    NonZeroI64 => i64,
+    NonZeroI128 => i128,
+    NonZeroIsize => isize,
+);
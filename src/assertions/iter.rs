@@ -1,5 +1,11 @@
 use crate::{implementation, private, Asserter};
-use std::fmt::Debug;
+use std::{
+    cmp::Ordering,
+    collections::HashMap,
+    fmt::{self, Debug},
+    hash::Hash,
+    iter::{Product, Sum},
+};
 
 /// Specifies various assertions on [`IntoIterator`]. Implemented on [`Asserter`]
 ///
@@ -175,6 +181,86 @@ where
     where
         Item: Debug;
 
+    /// Convenience function for accessing the last element of the Iterable.
+    ///
+    /// # Examples
+    /// ```
+    /// # use smoothy::prelude::*;
+    /// #
+    /// let vec = vec!["First", "Second", "Third"];
+    /// assert_that(vec).last().equals("Third");
+    /// ```
+    ///
+    /// ```should_panic
+    /// # use smoothy::prelude::*;
+    /// #
+    /// let vec: Vec<String> = vec![];
+    /// assert_that(vec).last().equals("Hello World!");
+    /// ```
+    ///
+    /// # Panics
+    /// When the Iterator is empty.
+    #[track_caller]
+    #[must_use = "Accessing the last element only asserts that size > 0. If you want to assert the size use assert_that(iter).size().equals(1) instead"]
+    fn last(self) -> Asserter<Item>
+    where
+        Item: Debug;
+
+    /// Convenience function for accessing the only element of the Iterable.
+    ///
+    /// Useful for query results which are expected to contain exactly one row.
+    ///
+    /// # Examples
+    /// ```
+    /// # use smoothy::prelude::*;
+    /// #
+    /// let vec = vec!["Hello World!"];
+    /// assert_that(vec).single().equals("Hello World!");
+    /// ```
+    ///
+    /// ```should_panic
+    /// # use smoothy::prelude::*;
+    /// #
+    /// let vec = vec!["Hello", "World!"];
+    /// assert_that(vec).single().equals("Hello");
+    /// ```
+    ///
+    /// # Panics
+    /// When the Iterator does not contain exactly one element.
+    #[track_caller]
+    #[must_use = "Accessing the single element only asserts that size == 1"]
+    fn single(self) -> Asserter<Item>
+    where
+        Item: Debug;
+
+    /// Convenience function for accessing the nth element of the Iterable counted from its end.
+    ///
+    /// The last element is the 0th element from the end.
+    ///
+    /// # Examples
+    /// ```
+    /// # use smoothy::prelude::*;
+    /// #
+    /// let vec = vec!["First", "Second", "Third"];
+    /// assert_that(vec.clone()).nth_from_end(0).equals("Third");
+    /// assert_that(vec).nth_from_end(2).equals("First");
+    /// ```
+    ///
+    /// ```should_panic
+    /// # use smoothy::prelude::*;
+    /// #
+    /// let vec = vec!["First", "Second", "Third"];
+    /// assert_that(vec).nth_from_end(3).equals("Hello World!");
+    /// ```
+    ///
+    /// # Panics
+    /// When the Iterator does not contain more than nth elements.
+    #[track_caller]
+    #[must_use = "Accessing the nth element from the end only asserts that size > nth. If you want to assert the size use assert_that(iter).size().equals(nth) instead"]
+    fn nth_from_end(self, nth: usize) -> Asserter<Item>
+    where
+        Item: Debug;
+
     /// Asserts that the iterable contains the item at least once in any place in the iterator
     ///
     /// [See top-level docs for more details on content assertions](index.html#content-assertions)
@@ -267,6 +353,68 @@ where
     where
         Item: Debug + PartialEq;
 
+    /// Asserts that the iterable contains the expected items in the given order. Other items may appear in between.
+    ///
+    /// [See top-level docs for more details on content assertions](index.html#content-assertions)
+    ///
+    /// # Examples
+    /// ```
+    /// # use smoothy::prelude::*;
+    /// #
+    /// let events = vec!["connect", "ping", "subscribe", "ping", "disconnect"];
+    ///
+    /// assert_that(events).contains_in_order(["connect", "subscribe", "disconnect"]);
+    /// ```
+    ///
+    /// ```should_panic
+    /// # use smoothy::prelude::*;
+    /// #
+    /// let events = vec!["connect", "ping", "subscribe", "ping", "disconnect"];
+    ///
+    /// assert_that(events).contains_in_order(["subscribe", "connect"]);
+    /// ```
+    ///
+    /// # Panics
+    /// When one of the expected items cannot be found after the ones preceding it.
+    #[track_caller]
+    fn contains_in_order(
+        self,
+        expected_items: impl IntoIterator<Item = impl Into<Item>>,
+    ) -> Asserter<Vec<Item>>
+    where
+        Item: Debug + PartialEq;
+
+    /// Asserts that the iterable contains the expected items in the given order without any items in between
+    ///
+    /// [See top-level docs for more details on content assertions](index.html#content-assertions)
+    ///
+    /// # Examples
+    /// ```
+    /// # use smoothy::prelude::*;
+    /// #
+    /// let events = vec!["connect", "ping", "subscribe", "ack", "ping"];
+    ///
+    /// assert_that(events).contains_in_sequence(["subscribe", "ack"]);
+    /// ```
+    ///
+    /// ```should_panic
+    /// # use smoothy::prelude::*;
+    /// #
+    /// let events = vec!["connect", "ping", "subscribe", "ping", "ack"];
+    ///
+    /// assert_that(events).contains_in_sequence(["subscribe", "ack"]);
+    /// ```
+    ///
+    /// # Panics
+    /// When the expected items do not appear back-to-back in the iterable.
+    #[track_caller]
+    fn contains_in_sequence(
+        self,
+        expected_items: impl IntoIterator<Item = impl Into<Item>>,
+    ) -> Asserter<Vec<Item>>
+    where
+        Item: Debug + PartialEq;
+
     /// Asserts that all elements in the iterable match the given predicate.
     ///
     /// Succeeds when the iterator is empty. Use [`is_not_empty`](IteratorAssertion::is_not_empty) first to assert that the iterator is empty.
@@ -344,89 +492,644 @@ where
     fn none_match(self, predicate: impl Fn(&Item) -> bool) -> Asserter<Vec<Item>>
     where
         Item: Debug;
-}
-
-impl<Iterable, Item> IteratorAssertion<Iterable, Item> for Asserter<Iterable>
-where
-    Iterable: IntoIterator<Item = Item>,
-{
-    fn size(self) -> Asserter<usize> {
-        let size = self.value.into_iter().count();
-        Asserter { value: size }
-    }
 
-    fn is_not_empty(self) -> Asserter<Vec<Item>>
+    /// Asserts that the items are sorted in ascending order. Equal items may follow each other.
+    ///
+    /// Succeeds when the iterable is empty or contains a single item.
+    ///
+    /// # Examples
+    /// ```
+    /// # use smoothy::prelude::*;
+    /// #
+    /// assert_that(vec![1, 2, 2, 3]).is_sorted();
+    /// ```
+    ///
+    /// ```should_panic
+    /// # use smoothy::prelude::*;
+    /// #
+    /// assert_that(vec![1, 3, 2]).is_sorted();
+    /// ```
+    ///
+    /// # Panics
+    /// When an item is greater than the one following it or the two cannot be compared.
+    #[track_caller]
+    #[allow(clippy::wrong_self_convention)]
+    fn is_sorted(self) -> Asserter<Vec<Item>>
     where
-        Item: Debug,
-    {
-        let actual = self.value.into_iter().collect::<Vec<Item>>();
-
-        implementation::assert_no_expected(
-            !actual.is_empty(),
-            &actual,
-            "to contain at least one item",
-        );
-
-        Asserter { value: actual }
-    }
+        Item: Debug + PartialOrd;
 
-    fn is_empty(self)
+    /// Asserts that the items are sorted in descending order. Equal items may follow each other.
+    ///
+    /// Succeeds when the iterable is empty or contains a single item.
+    ///
+    /// # Examples
+    /// ```
+    /// # use smoothy::prelude::*;
+    /// #
+    /// assert_that(vec![3, 2, 2, 1]).is_sorted_descending();
+    /// ```
+    ///
+    /// ```should_panic
+    /// # use smoothy::prelude::*;
+    /// #
+    /// assert_that(vec![3, 1, 2]).is_sorted_descending();
+    /// ```
+    ///
+    /// # Panics
+    /// When an item is less than the one following it or the two cannot be compared.
+    #[track_caller]
+    #[allow(clippy::wrong_self_convention)]
+    fn is_sorted_descending(self) -> Asserter<Vec<Item>>
     where
-        Item: Debug,
-    {
-        let actual = self.value.into_iter().collect::<Vec<Item>>();
-        implementation::assert_no_expected(actual.is_empty(), &actual, "to be empty");
-    }
+        Item: Debug + PartialOrd;
 
-    fn first(self) -> Asserter<Item>
+    /// Asserts that the items are sorted in ascending order without any equal items following each other.
+    ///
+    /// Succeeds when the iterable is empty or contains a single item.
+    ///
+    /// # Examples
+    /// ```
+    /// # use smoothy::prelude::*;
+    /// #
+    /// assert_that(vec![1, 2, 3]).is_strictly_sorted();
+    /// ```
+    ///
+    /// ```should_panic
+    /// # use smoothy::prelude::*;
+    /// #
+    /// assert_that(vec![1, 2, 2, 3]).is_strictly_sorted();
+    /// ```
+    ///
+    /// # Panics
+    /// When an item is greater than or equal to the one following it or the two cannot be compared.
+    #[track_caller]
+    #[allow(clippy::wrong_self_convention)]
+    fn is_strictly_sorted(self) -> Asserter<Vec<Item>>
     where
-        Item: Debug,
-    {
-        let mut actual = self.value.into_iter();
-        let maybe_item = actual.nth(0);
-
-        implementation::assert_no_expected(
-            maybe_item.is_some(),
-            actual.collect::<Vec<Item>>(),
-            "to contain a first item",
-        );
-
-        #[allow(clippy::unwrap_used)]
-        let item = maybe_item.unwrap();
+        Item: Debug + PartialOrd;
 
-        Asserter { value: item }
-    }
-
-    fn second(self) -> Asserter<Item>
+    /// Asserts that each pair of adjacent items is in order according to the given function.
+    ///
+    /// The function is called with an item and the one following it and returns whether they are in order.
+    ///
+    /// # Examples
+    /// ```
+    /// # use smoothy::prelude::*;
+    /// #
+    /// let words = vec!["a", "bb", "cc", "ddd"];
+    /// assert_that(words).is_sorted_by(|first, second| first.len() <= second.len());
+    /// ```
+    ///
+    /// ```should_panic
+    /// # use smoothy::prelude::*;
+    /// #
+    /// let words = vec!["a", "ddd", "bb"];
+    /// assert_that(words).is_sorted_by(|first, second| first.len() <= second.len());
+    /// ```
+    ///
+    /// # Panics
+    /// When the function returns false for a pair of adjacent items.
+    #[track_caller]
+    #[allow(clippy::wrong_self_convention)]
+    fn is_sorted_by(self, in_order: impl FnMut(&Item, &Item) -> bool) -> Asserter<Vec<Item>>
     where
-        Item: Debug,
-    {
-        let mut actual = self.value.into_iter();
-        let maybe_item = actual.nth(1);
-
-        implementation::assert_no_expected(
-            maybe_item.is_some(),
-            actual.collect::<Vec<Item>>(),
-            "to contain a second item",
-        );
-
-        #[allow(clippy::unwrap_used)]
-        let item = maybe_item.unwrap();
-
-        Asserter { value: item }
-    }
+        Item: Debug;
 
-    fn third(self) -> Asserter<Item>
+    /// Asserts that the items are sorted in ascending order of the key extracted by the given function.
+    ///
+    /// # Examples
+    /// ```
+    /// # use smoothy::prelude::*;
+    /// #
+    /// let ranking = vec![("Alice", 3), ("Bob", 5), ("Carol", 8)];
+    /// assert_that(ranking).is_sorted_by_key(|(_name, score)| *score);
+    /// ```
+    ///
+    /// ```should_panic
+    /// # use smoothy::prelude::*;
+    /// #
+    /// let ranking = vec![("Alice", 3), ("Bob", 8), ("Carol", 5)];
+    /// assert_that(ranking).is_sorted_by_key(|(_name, score)| *score);
+    /// ```
+    ///
+    /// # Panics
+    /// When the key of an item is greater than the key of the one following it or the two cannot be compared.
+    #[track_caller]
+    #[allow(clippy::wrong_self_convention)]
+    fn is_sorted_by_key<Key>(self, key: impl FnMut(&Item) -> Key) -> Asserter<Vec<Item>>
     where
         Item: Debug,
-    {
-        let mut actual = self.value.into_iter();
-        let maybe_item = actual.nth(2);
+        Key: PartialOrd;
 
-        implementation::assert_no_expected(
-            maybe_item.is_some(),
-            actual.collect::<Vec<Item>>(),
-            "to contain a third item",
+    /// Asserts that no item of the iterable is equal to another one
+    ///
+    /// Items are compared pairwise, so the runtime grows quadratically with the number of distinct items.
+    /// Use [`has_unique_elements_by_hash`](IteratorAssertion::has_unique_elements_by_hash) for large iterables of hashable items.
+    ///
+    /// # Examples
+    /// ```
+    /// # use smoothy::prelude::*;
+    /// #
+    /// assert_that(vec![1, 2, 3]).has_unique_elements();
+    /// ```
+    ///
+    /// ```should_panic
+    /// # use smoothy::prelude::*;
+    /// #
+    /// assert_that(vec![1, 2, 1, 3]).has_unique_elements();
+    /// ```
+    ///
+    /// # Panics
+    /// When an item occurs more than once.
+    #[track_caller]
+    fn has_unique_elements(self) -> Asserter<Vec<Item>>
+    where
+        Item: Debug + PartialEq;
+
+    /// Asserts that no two items of the iterable have the same key extracted by the given function
+    ///
+    /// Keys are compared pairwise, so the runtime grows quadratically with the number of distinct keys.
+    ///
+    /// # Examples
+    /// ```
+    /// # use smoothy::prelude::*;
+    /// #
+    /// let users = vec![(1, "alice"), (2, "bob")];
+    /// assert_that(users).has_unique_elements_by_key(|(id, _name)| *id);
+    /// ```
+    ///
+    /// ```should_panic
+    /// # use smoothy::prelude::*;
+    /// #
+    /// let users = vec![(1, "alice"), (2, "bob"), (1, "carol")];
+    /// assert_that(users).has_unique_elements_by_key(|(id, _name)| *id);
+    /// ```
+    ///
+    /// # Panics
+    /// When a key occurs more than once.
+    #[track_caller]
+    fn has_unique_elements_by_key<Key>(self, key: impl FnMut(&Item) -> Key) -> Asserter<Vec<Item>>
+    where
+        Item: Debug,
+        Key: Debug + PartialEq;
+
+    /// Asserts that no item of the iterable is equal to another one by hashing the items
+    ///
+    /// In contrast to [`has_unique_elements`](IteratorAssertion::has_unique_elements)
+    /// the runtime grows linearly with the number of items. Hashing is opt-in as stable Rust
+    /// cannot pick the implementation depending on whether the items are [`Hash`].
+    ///
+    /// # Examples
+    /// ```
+    /// # use smoothy::prelude::*;
+    /// #
+    /// assert_that(0..10_000).has_unique_elements_by_hash();
+    /// ```
+    ///
+    /// ```should_panic
+    /// # use smoothy::prelude::*;
+    /// #
+    /// assert_that(vec!["a", "b", "a"]).has_unique_elements_by_hash();
+    /// ```
+    ///
+    /// # Panics
+    /// When an item occurs more than once.
+    #[track_caller]
+    fn has_unique_elements_by_hash(self) -> Asserter<Vec<Item>>
+    where
+        Item: Debug + Hash + Eq;
+
+    /// Convenience function for accessing the items which occur more than once
+    ///
+    /// Each duplicated item is contained once in the order of its first occurrence.
+    ///
+    /// Items are compared pairwise, so the runtime grows quadratically with the number of distinct items.
+    /// Use [`duplicates_by_hash`](IteratorAssertion::duplicates_by_hash) for large iterables of hashable items.
+    ///
+    /// # Examples
+    /// ```
+    /// # use smoothy::prelude::*;
+    /// #
+    /// assert_that(vec![1, 2, 1, 3, 2, 1])
+    ///     .duplicates()
+    ///     .is(vec![1, 2]);
+    /// assert_that(vec![1, 2, 3]).duplicates().is_empty();
+    /// ```
+    #[track_caller]
+    #[must_use = "Accessing the duplicated items does not assert anything"]
+    fn duplicates(self) -> Asserter<Vec<Item>>
+    where
+        Item: PartialEq;
+
+    /// Convenience function for accessing the items which occur more than once by hashing the items
+    ///
+    /// Returns the same items as [`duplicates`](IteratorAssertion::duplicates)
+    /// but the runtime grows linearly with the number of items.
+    ///
+    /// # Examples
+    /// ```
+    /// # use smoothy::prelude::*;
+    /// #
+    /// assert_that(vec!["b", "a", "b", "c", "a"])
+    ///     .duplicates_by_hash()
+    ///     .is(vec!["b", "a"]);
+    /// ```
+    #[track_caller]
+    #[must_use = "Accessing the duplicated items does not assert anything"]
+    fn duplicates_by_hash(self) -> Asserter<Vec<Item>>
+    where
+        Item: Hash + Eq;
+
+    /// Convenience function for getting the sum of all items of the Iterable.
+    ///
+    /// # Examples
+    /// ```
+    /// # use smoothy::prelude::*;
+    /// #
+    /// let line_items = vec![10, 20, 12];
+    /// assert_that(line_items).sum().is(42);
+    /// ```
+    ///
+    /// ```should_panic
+    /// # use smoothy::prelude::*;
+    /// #
+    /// let line_items: Vec<u32> = vec![];
+    /// assert_that(line_items).sum().is(0);
+    /// ```
+    ///
+    /// # Panics
+    /// When the Iterable is empty.
+    #[track_caller]
+    #[must_use = "Accessing the sum only asserts that the iterable is not empty"]
+    fn sum(self) -> Asserter<Item>
+    where
+        Item: Debug + Sum;
+
+    /// Convenience function for getting the product of all items of the Iterable.
+    ///
+    /// # Examples
+    /// ```
+    /// # use smoothy::prelude::*;
+    /// #
+    /// let factors = vec![2, 3, 7];
+    /// assert_that(factors).product().is(42);
+    /// ```
+    ///
+    /// ```should_panic
+    /// # use smoothy::prelude::*;
+    /// #
+    /// let factors: Vec<u32> = vec![];
+    /// assert_that(factors).product().is(1);
+    /// ```
+    ///
+    /// # Panics
+    /// When the Iterable is empty.
+    #[track_caller]
+    #[must_use = "Accessing the product only asserts that the iterable is not empty"]
+    fn product(self) -> Asserter<Item>
+    where
+        Item: Debug + Product;
+
+    /// Convenience function for accessing the smallest item of the Iterable.
+    ///
+    /// If several items are equally small the first one is returned.
+    ///
+    /// # Examples
+    /// ```
+    /// # use smoothy::prelude::*;
+    /// #
+    /// let latencies = vec![120, 80, 95];
+    /// assert_that(latencies).min().is(80);
+    ///
+    /// let latencies = vec![0.12, 0.08, 0.095];
+    /// assert_that(latencies).min().is(0.08);
+    /// ```
+    ///
+    /// ```should_panic
+    /// # use smoothy::prelude::*;
+    /// #
+    /// let latencies: Vec<u32> = vec![];
+    /// assert_that(latencies).min().is(80);
+    /// ```
+    ///
+    /// # Panics
+    /// When the Iterable is empty or contains items that cannot be compared like [`f64::NAN`].
+    #[track_caller]
+    #[must_use = "Accessing the smallest item only asserts that the iterable is not empty"]
+    fn min(self) -> Asserter<Item>
+    where
+        Item: Debug + PartialOrd;
+
+    /// Convenience function for accessing the largest item of the Iterable.
+    ///
+    /// If several items are equally large the last one is returned.
+    ///
+    /// # Examples
+    /// ```
+    /// # use smoothy::prelude::*;
+    /// #
+    /// let latencies = vec![120, 80, 95];
+    /// assert_that(latencies).max().is(120);
+    ///
+    /// let latencies = vec![0.12, 0.08, 0.095];
+    /// assert_that(latencies).max().is(0.12);
+    /// ```
+    ///
+    /// ```should_panic
+    /// # use smoothy::prelude::*;
+    /// #
+    /// let latencies: Vec<u32> = vec![];
+    /// assert_that(latencies).max().is(120);
+    /// ```
+    ///
+    /// # Panics
+    /// When the Iterable is empty or contains items that cannot be compared like [`f64::NAN`].
+    #[track_caller]
+    #[must_use = "Accessing the largest item only asserts that the iterable is not empty"]
+    fn max(self) -> Asserter<Item>
+    where
+        Item: Debug + PartialOrd;
+
+    /// Convenience function for accessing the item of the Iterable with the smallest key.
+    ///
+    /// If several items have an equally small key the first one is returned.
+    ///
+    /// # Examples
+    /// ```
+    /// # use smoothy::prelude::*;
+    /// #
+    /// let words = vec!["Hello", "World", "!"];
+    /// assert_that(words).min_by_key(|word| word.len()).is("!");
+    /// ```
+    ///
+    /// ```should_panic
+    /// # use smoothy::prelude::*;
+    /// #
+    /// let words: Vec<&str> = vec![];
+    /// assert_that(words).min_by_key(|word| word.len()).is("!");
+    /// ```
+    ///
+    /// # Panics
+    /// When the Iterable is empty or contains items whose keys cannot be compared like [`f64::NAN`].
+    #[track_caller]
+    #[must_use = "Accessing the item with the smallest key only asserts that the iterable is not empty"]
+    fn min_by_key<Key>(self, key: impl FnMut(&Item) -> Key) -> Asserter<Item>
+    where
+        Item: Debug,
+        Key: Debug + PartialOrd;
+
+    /// Convenience function for accessing the item of the Iterable with the largest key.
+    ///
+    /// If several items have an equally large key the last one is returned.
+    ///
+    /// # Examples
+    /// ```
+    /// # use smoothy::prelude::*;
+    /// #
+    /// let words = vec!["Hello", "World", "!"];
+    /// assert_that(words).max_by_key(|word| word.len()).is("World");
+    /// ```
+    ///
+    /// ```should_panic
+    /// # use smoothy::prelude::*;
+    /// #
+    /// let words: Vec<&str> = vec![];
+    /// assert_that(words).max_by_key(|word| word.len()).is("World");
+    /// ```
+    ///
+    /// # Panics
+    /// When the Iterable is empty or contains items whose keys cannot be compared like [`f64::NAN`].
+    #[track_caller]
+    #[must_use = "Accessing the item with the largest key only asserts that the iterable is not empty"]
+    fn max_by_key<Key>(self, key: impl FnMut(&Item) -> Key) -> Asserter<Item>
+    where
+        Item: Debug,
+        Key: Debug + PartialOrd;
+
+    /// Convenience function for getting the arithmetic mean of all items of the Iterable as [f64].
+    ///
+    /// Available for all primitive integer and floating point types, see [`IntoF64`].
+    ///
+    /// # Examples
+    /// ```
+    /// # use smoothy::prelude::*;
+    /// #
+    /// let response_times = vec![0.5, 1.0, 1.5];
+    /// assert_that(response_times).average().is(1.0);
+    ///
+    /// let line_items: Vec<u64> = vec![1_999, 4_999, 501];
+    /// assert_that(line_items)
+    ///     .average()
+    ///     .is(2_499.666_666_666_666_5);
+    /// ```
+    ///
+    /// ```should_panic
+    /// # use smoothy::prelude::*;
+    /// #
+    /// let response_times: Vec<f64> = vec![];
+    /// assert_that(response_times).average().is(1.0);
+    /// ```
+    ///
+    /// # Panics
+    /// When the Iterable is empty.
+    #[track_caller]
+    #[must_use = "Accessing the average only asserts that the iterable is not empty"]
+    fn average(self) -> Asserter<f64>
+    where
+        Item: Debug + IntoF64;
+
+    /// Convenience function for mapping every item of the Iterable for further assertions
+    ///
+    /// # Examples
+    /// ```
+    /// # use smoothy::prelude::*;
+    /// #
+    /// struct User {
+    ///     name: String,
+    ///     age: u8,
+    /// }
+    ///
+    /// let users = vec![
+    ///     User {
+    ///         name: "Alice".to_string(),
+    ///         age: 32,
+    ///     },
+    ///     User {
+    ///         name: "Bob".to_string(),
+    ///         age: 27,
+    ///     },
+    /// ];
+    ///
+    /// assert_that(users)
+    ///     .extracting(|user| user.name)
+    ///     .contains_only(["Bob", "Alice"]);
+    /// ```
+    #[track_caller]
+    #[must_use = "Transforming the asserted value does not assert anything"]
+    fn extracting<Extracted>(
+        self,
+        extractor: impl FnMut(Item) -> Extracted,
+    ) -> Asserter<Vec<Extracted>>;
+
+    /// Convenience function for keeping only the items of the Iterable matching the predicate
+    ///
+    /// # Examples
+    /// ```
+    /// # use smoothy::prelude::*;
+    /// #
+    /// let numbers = vec![1, 2, 3, 4, 5];
+    /// assert_that(numbers).filtered(|x| x % 2 == 0).is(vec![2, 4]);
+    /// ```
+    #[track_caller]
+    #[must_use = "Transforming the asserted value does not assert anything"]
+    fn filtered(self, predicate: impl FnMut(&Item) -> bool) -> Asserter<Vec<Item>>;
+
+    /// Convenience function for concatenating the nested iterables of the Iterable
+    ///
+    /// # Examples
+    /// ```
+    /// # use smoothy::prelude::*;
+    /// #
+    /// let pages = vec![vec![1, 2], vec![], vec![3]];
+    /// assert_that(pages).flattened().is(vec![1, 2, 3]);
+    /// ```
+    #[track_caller]
+    #[must_use = "Transforming the asserted value does not assert anything"]
+    fn flattened(self) -> Asserter<Vec<Item::Item>>
+    where
+        Item: IntoIterator;
+
+    /// Convenience function for sorting the items of the Iterable in ascending order
+    ///
+    /// The sort is stable, so equal items keep their order.
+    ///
+    /// # Examples
+    /// ```
+    /// # use smoothy::prelude::*;
+    /// #
+    /// let numbers = vec![3, 1, 2];
+    /// assert_that(numbers).sorted().is(vec![1, 2, 3]);
+    /// ```
+    #[track_caller]
+    #[must_use = "Transforming the asserted value does not assert anything"]
+    fn sorted(self) -> Asserter<Vec<Item>>
+    where
+        Item: Ord;
+
+    /// Convenience function for sorting the items of the Iterable in ascending order of the key extracted by the given function
+    ///
+    /// The sort is stable, so items with equal keys keep their order.
+    ///
+    /// # Examples
+    /// ```
+    /// # use smoothy::prelude::*;
+    /// #
+    /// let words = vec!["ccc", "a", "bb"];
+    /// assert_that(words)
+    ///     .sorted_by_key(|word| word.len())
+    ///     .is(vec!["a", "bb", "ccc"]);
+    /// ```
+    #[track_caller]
+    #[must_use = "Transforming the asserted value does not assert anything"]
+    fn sorted_by_key<Key>(self, key: impl FnMut(&Item) -> Key) -> Asserter<Vec<Item>>
+    where
+        Key: Ord;
+
+    /// Convenience function for reversing the order of the items of the Iterable
+    ///
+    /// # Examples
+    /// ```
+    /// # use smoothy::prelude::*;
+    /// #
+    /// let numbers = vec![1, 2, 3];
+    /// assert_that(numbers).reversed().is(vec![3, 2, 1]);
+    /// ```
+    #[track_caller]
+    #[must_use = "Transforming the asserted value does not assert anything"]
+    fn reversed(self) -> Asserter<Vec<Item>>;
+}
+
+impl<Iterable, Item> IteratorAssertion<Iterable, Item> for Asserter<Iterable>
+where
+    Iterable: IntoIterator<Item = Item>,
+{
+    fn size(self) -> Asserter<usize> {
+        let size = self.value.into_iter().count();
+        Asserter { value: size }
+    }
+
+    fn is_not_empty(self) -> Asserter<Vec<Item>>
+    where
+        Item: Debug,
+    {
+        let actual = self.value.into_iter().collect::<Vec<Item>>();
+
+        implementation::assert_no_expected(
+            !actual.is_empty(),
+            &actual,
+            "to contain at least one item",
+        );
+
+        Asserter { value: actual }
+    }
+
+    fn is_empty(self)
+    where
+        Item: Debug,
+    {
+        let actual = self.value.into_iter().collect::<Vec<Item>>();
+        implementation::assert_no_expected(actual.is_empty(), &actual, "to be empty");
+    }
+
+    fn first(self) -> Asserter<Item>
+    where
+        Item: Debug,
+    {
+        let mut actual = self.value.into_iter();
+        let maybe_item = actual.nth(0);
+
+        implementation::assert_no_expected(
+            maybe_item.is_some(),
+            actual.collect::<Vec<Item>>(),
+            "to contain a first item",
+        );
+
+        #[allow(clippy::unwrap_used)]
+        let item = maybe_item.unwrap();
+
+        Asserter { value: item }
+    }
+
+    fn second(self) -> Asserter<Item>
+    where
+        Item: Debug,
+    {
+        let mut actual = self.value.into_iter();
+        let maybe_item = actual.nth(1);
+
+        implementation::assert_no_expected(
+            maybe_item.is_some(),
+            actual.collect::<Vec<Item>>(),
+            "to contain a second item",
+        );
+
+        #[allow(clippy::unwrap_used)]
+        let item = maybe_item.unwrap();
+
+        Asserter { value: item }
+    }
+
+    fn third(self) -> Asserter<Item>
+    where
+        Item: Debug,
+    {
+        let mut actual = self.value.into_iter();
+        let maybe_item = actual.nth(2);
+
+        implementation::assert_no_expected(
+            maybe_item.is_some(),
+            actual.collect::<Vec<Item>>(),
+            "to contain a third item",
         );
 
         #[allow(clippy::unwrap_used)]
@@ -454,6 +1157,44 @@ where
         Asserter { value: item }
     }
 
+    fn last(self) -> Asserter<Item>
+    where
+        Item: Debug,
+    {
+        at_index_from_end(self.value, 0, "to contain a last item")
+    }
+
+    fn single(self) -> Asserter<Item>
+    where
+        Item: Debug,
+    {
+        let actual = self.value.into_iter().collect::<Vec<Item>>();
+
+        implementation::assert_with_additional_info_no_expected(
+            actual.len() == 1,
+            &actual,
+            "to contain exactly one item",
+            "but its size is",
+            actual.len(),
+        );
+
+        #[allow(clippy::unwrap_used)]
+        let item = actual.into_iter().next().unwrap();
+
+        Asserter { value: item }
+    }
+
+    fn nth_from_end(self, nth: usize) -> Asserter<Item>
+    where
+        Item: Debug,
+    {
+        at_index_from_end(
+            self.value,
+            nth,
+            &format!("to contain a {nth}th item from the end"),
+        )
+    }
+
     fn contains(self, expected: impl Into<Item>) -> Asserter<Vec<Item>>
     where
         Item: Debug + PartialEq,
@@ -555,9 +1296,111 @@ where
                 .collect::<Vec<&Item>>(),
         );
 
-        Asserter {
-            value: actual_items,
+        Asserter {
+            value: actual_items,
+        }
+    }
+
+    fn contains_in_order(
+        self,
+        expected: impl IntoIterator<Item = impl Into<Item>>,
+    ) -> Asserter<Vec<Item>>
+    where
+        Item: Debug + PartialEq,
+    {
+        let actual = self.value.into_iter().collect::<Vec<Item>>();
+        let expected_items = expected.into_iter().map(Into::into).collect::<Vec<Item>>();
+
+        // matching each expected item with its first occurrence finds the longest matching prefix
+        let mut indices = Vec::with_capacity(expected_items.len());
+        let mut search_start = 0;
+        for expected_item in &expected_items {
+            let found = actual
+                .iter()
+                .skip(search_start)
+                .position(|item| item == expected_item);
+
+            match found {
+                Some(offset) => {
+                    indices.push(search_start + offset);
+                    search_start += offset + 1;
+                }
+                None => break,
+            }
+        }
+
+        if let Some(missing) = expected_items.get(indices.len()) {
+            let matched = expected_items.get(..indices.len()).unwrap_or_default();
+            let description = if matched.is_empty() {
+                String::from("but could not find")
+            } else {
+                format!("but after finding {matched:?} at the indices {indices:?} could not find")
+            };
+
+            implementation::assert_with_additional_info(
+                false,
+                &actual,
+                "to contain in order",
+                &expected_items,
+                &description,
+                missing,
+            );
+        }
+
+        Asserter { value: actual }
+    }
+
+    fn contains_in_sequence(
+        self,
+        expected: impl IntoIterator<Item = impl Into<Item>>,
+    ) -> Asserter<Vec<Item>>
+    where
+        Item: Debug + PartialEq,
+    {
+        let actual = self.value.into_iter().collect::<Vec<Item>>();
+        let expected_items = expected.into_iter().map(Into::into).collect::<Vec<Item>>();
+
+        // the window matching the most leading expected items is the closest one
+        let mut closest_start = 0;
+        let mut closest_length = 0;
+        for start in 0..actual.len() {
+            let length = actual
+                .iter()
+                .skip(start)
+                .zip(&expected_items)
+                .take_while(|(item, expected_item)| item == expected_item)
+                .count();
+
+            if length > closest_length {
+                closest_start = start;
+                closest_length = length;
+            }
+        }
+
+        if let Some(expected_item) = expected_items.get(closest_length) {
+            let matched = expected_items.get(..closest_length).unwrap_or_default();
+            let diverged_at = closest_start + closest_length;
+            let description = match actual.get(diverged_at) {
+                _ if matched.is_empty() => String::from("but could not find"),
+                Some(found) => format!(
+                    "but the closest window at index {closest_start} matches {matched:?} and has {found:?} at index {diverged_at} instead of"
+                ),
+                None => format!(
+                    "but the closest window at index {closest_start} matches {matched:?} and ends before"
+                ),
+            };
+
+            implementation::assert_with_additional_info(
+                false,
+                &actual,
+                "to contain in sequence",
+                &expected_items,
+                &description,
+                expected_item,
+            );
         }
+
+        Asserter { value: actual }
     }
 
     fn all_match(self, predicate: impl Fn(&Item) -> bool) -> Asserter<Vec<Item>>
@@ -620,4 +1463,581 @@ where
 
         Asserter { value: actual }
     }
+
+    fn is_sorted(self) -> Asserter<Vec<Item>>
+    where
+        Item: Debug + PartialOrd,
+    {
+        assert_sorted(
+            self.value,
+            "to be sorted in ascending order",
+            |first, second| first <= second,
+        )
+    }
+
+    fn is_sorted_descending(self) -> Asserter<Vec<Item>>
+    where
+        Item: Debug + PartialOrd,
+    {
+        assert_sorted(
+            self.value,
+            "to be sorted in descending order",
+            |first, second| first >= second,
+        )
+    }
+
+    fn is_strictly_sorted(self) -> Asserter<Vec<Item>>
+    where
+        Item: Debug + PartialOrd,
+    {
+        assert_sorted(
+            self.value,
+            "to be strictly sorted in ascending order",
+            |first, second| first < second,
+        )
+    }
+
+    fn is_sorted_by(self, in_order: impl FnMut(&Item, &Item) -> bool) -> Asserter<Vec<Item>>
+    where
+        Item: Debug,
+    {
+        assert_sorted(self.value, "to be sorted by the function", in_order)
+    }
+
+    fn is_sorted_by_key<Key>(self, mut key: impl FnMut(&Item) -> Key) -> Asserter<Vec<Item>>
+    where
+        Item: Debug,
+        Key: PartialOrd,
+    {
+        assert_sorted(
+            self.value,
+            "to be sorted by the key in ascending order",
+            |first, second| key(first) <= key(second),
+        )
+    }
+
+    fn has_unique_elements(self) -> Asserter<Vec<Item>>
+    where
+        Item: Debug + PartialEq,
+    {
+        let actual = self.value.into_iter().collect::<Vec<Item>>();
+
+        let duplicates = Duplicates::of(actual.iter());
+
+        implementation::assert_with_additional_info_no_expected(
+            duplicates.0.is_empty(),
+            &actual,
+            "to have unique elements",
+            "but found duplicates",
+            duplicates,
+        );
+
+        Asserter { value: actual }
+    }
+
+    fn has_unique_elements_by_key<Key>(self, key: impl FnMut(&Item) -> Key) -> Asserter<Vec<Item>>
+    where
+        Item: Debug,
+        Key: Debug + PartialEq,
+    {
+        let actual = self.value.into_iter().collect::<Vec<Item>>();
+
+        let duplicates = Duplicates::of(actual.iter().map(key));
+
+        implementation::assert_with_additional_info_no_expected(
+            duplicates.0.is_empty(),
+            &actual,
+            "to have elements with unique keys",
+            "but found duplicate keys",
+            duplicates,
+        );
+
+        Asserter { value: actual }
+    }
+
+    fn has_unique_elements_by_hash(self) -> Asserter<Vec<Item>>
+    where
+        Item: Debug + Hash + Eq,
+    {
+        let actual = self.value.into_iter().collect::<Vec<Item>>();
+
+        let duplicates = Duplicates::of_hashable(actual.iter());
+
+        implementation::assert_with_additional_info_no_expected(
+            duplicates.0.is_empty(),
+            &actual,
+            "to have unique elements",
+            "but found duplicates",
+            duplicates,
+        );
+
+        Asserter { value: actual }
+    }
+
+    fn duplicates(self) -> Asserter<Vec<Item>>
+    where
+        Item: PartialEq,
+    {
+        let actual = self.value.into_iter().collect::<Vec<Item>>();
+
+        let is_first_occurrence = Duplicates::of(actual.iter()).first_occurrences(actual.len());
+
+        Asserter {
+            value: keep_marked(actual, is_first_occurrence),
+        }
+    }
+
+    fn duplicates_by_hash(self) -> Asserter<Vec<Item>>
+    where
+        Item: Hash + Eq,
+    {
+        let actual = self.value.into_iter().collect::<Vec<Item>>();
+
+        let is_first_occurrence =
+            Duplicates::of_hashable(actual.iter()).first_occurrences(actual.len());
+
+        Asserter {
+            value: keep_marked(actual, is_first_occurrence),
+        }
+    }
+
+    fn sum(self) -> Asserter<Item>
+    where
+        Item: Debug + Sum,
+    {
+        Asserter {
+            value: non_empty(self.value, "the sum").into_iter().sum(),
+        }
+    }
+
+    fn product(self) -> Asserter<Item>
+    where
+        Item: Debug + Product,
+    {
+        Asserter {
+            value: non_empty(self.value, "the product").into_iter().product(),
+        }
+    }
+
+    fn min(self) -> Asserter<Item>
+    where
+        Item: Debug + PartialOrd,
+    {
+        let aggregate = "the smallest item";
+        let actual = non_empty(self.value, aggregate);
+
+        let index = extreme_index(&actual, &actual, "items", aggregate, Ordering::is_lt);
+
+        item_at(actual, index)
+    }
+
+    fn max(self) -> Asserter<Item>
+    where
+        Item: Debug + PartialOrd,
+    {
+        let aggregate = "the largest item";
+        let actual = non_empty(self.value, aggregate);
+
+        let index = extreme_index(&actual, &actual, "items", aggregate, Ordering::is_ge);
+
+        item_at(actual, index)
+    }
+
+    fn min_by_key<Key>(self, key: impl FnMut(&Item) -> Key) -> Asserter<Item>
+    where
+        Item: Debug,
+        Key: Debug + PartialOrd,
+    {
+        let aggregate = "the item with the smallest key";
+        let actual = non_empty(self.value, aggregate);
+        let keys = actual.iter().map(key).collect::<Vec<Key>>();
+
+        let index = extreme_index(&actual, &keys, "keys", aggregate, Ordering::is_lt);
+
+        item_at(actual, index)
+    }
+
+    fn max_by_key<Key>(self, key: impl FnMut(&Item) -> Key) -> Asserter<Item>
+    where
+        Item: Debug,
+        Key: Debug + PartialOrd,
+    {
+        let aggregate = "the item with the largest key";
+        let actual = non_empty(self.value, aggregate);
+        let keys = actual.iter().map(key).collect::<Vec<Key>>();
+
+        let index = extreme_index(&actual, &keys, "keys", aggregate, Ordering::is_ge);
+
+        item_at(actual, index)
+    }
+
+    fn average(self) -> Asserter<f64>
+    where
+        Item: Debug + IntoF64,
+    {
+        let actual = non_empty(self.value, "the average");
+
+        let (sum, count) = actual.into_iter().fold((0.0, 0.0), |(sum, count), item| {
+            (sum + item.into_f64(), count + 1.0)
+        });
+
+        Asserter { value: sum / count }
+    }
+
+    fn extracting<Extracted>(
+        self,
+        extractor: impl FnMut(Item) -> Extracted,
+    ) -> Asserter<Vec<Extracted>> {
+        Asserter {
+            value: self.value.into_iter().map(extractor).collect(),
+        }
+    }
+
+    fn filtered(self, predicate: impl FnMut(&Item) -> bool) -> Asserter<Vec<Item>> {
+        Asserter {
+            value: self.value.into_iter().filter(predicate).collect(),
+        }
+    }
+
+    fn flattened(self) -> Asserter<Vec<Item::Item>>
+    where
+        Item: IntoIterator,
+    {
+        Asserter {
+            value: self.value.into_iter().flatten().collect(),
+        }
+    }
+
+    fn sorted(self) -> Asserter<Vec<Item>>
+    where
+        Item: Ord,
+    {
+        let mut items = self.value.into_iter().collect::<Vec<Item>>();
+        items.sort();
+
+        Asserter { value: items }
+    }
+
+    fn sorted_by_key<Key>(self, key: impl FnMut(&Item) -> Key) -> Asserter<Vec<Item>>
+    where
+        Key: Ord,
+    {
+        let mut items = self.value.into_iter().collect::<Vec<Item>>();
+        items.sort_by_key(key);
+
+        Asserter { value: items }
+    }
+
+    fn reversed(self) -> Asserter<Vec<Item>> {
+        let mut items = self.value.into_iter().collect::<Vec<Item>>();
+        items.reverse();
+
+        Asserter { value: items }
+    }
+}
+
+/// Collects the Iterable and asserts that each pair of adjacent items is in order
+#[track_caller]
+fn assert_sorted<Iterable, Item>(
+    iterable: Iterable,
+    order: &str,
+    mut in_order: impl FnMut(&Item, &Item) -> bool,
+) -> Asserter<Vec<Item>>
+where
+    Iterable: IntoIterator<Item = Item>,
+    Item: Debug,
+{
+    let actual = iterable.into_iter().collect::<Vec<Item>>();
+
+    let unordered = actual.windows(2).enumerate().find_map(|(index, pair)| {
+        match pair {
+            [first, second] if !in_order(first, second) => {
+                Some(Unordered {
+                    index,
+                    first,
+                    second,
+                })
+            }
+            _ => None,
+        }
+    });
+
+    if let Some(unordered_pair) = unordered {
+        implementation::assert_with_additional_info_no_expected(
+            false,
+            &actual,
+            order,
+            "but found",
+            unordered_pair,
+        );
+    }
+
+    Asserter { value: actual }
+}
+
+/// Collects the Iterable and asserts that it contains the item at the index counted from its end
+#[track_caller]
+fn at_index_from_end<Iterable, Item>(
+    iterable: Iterable,
+    index_from_end: usize,
+    assertion_desc: &str,
+) -> Asserter<Item>
+where
+    Iterable: IntoIterator<Item = Item>,
+    Item: Debug,
+{
+    let actual = iterable.into_iter().collect::<Vec<Item>>();
+    let maybe_index = actual
+        .len()
+        .checked_sub(index_from_end)
+        .and_then(|remaining| remaining.checked_sub(1));
+
+    implementation::assert_with_additional_info_no_expected(
+        maybe_index.is_some(),
+        &actual,
+        assertion_desc,
+        "but its size is",
+        actual.len(),
+    );
+
+    #[allow(clippy::unwrap_used)]
+    let item = actual.into_iter().nth(maybe_index.unwrap()).unwrap();
+
+    Asserter { value: item }
+}
+
+/// The first pair of adjacent items that violates the order
+struct Unordered<'a, Item> {
+    index: usize,
+    first: &'a Item,
+    second: &'a Item,
+}
+
+impl<Item: Debug> Debug for Unordered<'_, Item> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{:?} at index {} before {:?} at index {}",
+            self.first,
+            self.index,
+            self.second,
+            self.index + 1
+        )
+    }
+}
+
+/// The values occurring more than once with all the indices they occur at in the order of their first occurrence
+struct Duplicates<Value>(Vec<(Value, Vec<usize>)>);
+
+impl<Value: PartialEq> Duplicates<Value> {
+    fn of(values: impl IntoIterator<Item = Value>) -> Self {
+        let mut occurrences: Vec<(Value, Vec<usize>)> = Vec::new();
+
+        for (index, value) in values.into_iter().enumerate() {
+            match occurrences
+                .iter_mut()
+                .find(|(occurred, _indices)| *occurred == value)
+            {
+                Some((_occurred, indices)) => indices.push(index),
+                None => occurrences.push((value, vec![index])),
+            }
+        }
+
+        occurrences.retain(|(_value, indices)| indices.len() > 1);
+
+        Self(occurrences)
+    }
+}
+
+impl<Value: Hash + Eq> Duplicates<Value> {
+    /// Like [`Duplicates::of`] but in linear instead of quadratic time
+    fn of_hashable(values: impl IntoIterator<Item = Value>) -> Self {
+        let mut occurrences: HashMap<Value, Vec<usize>> = HashMap::new();
+
+        for (index, value) in values.into_iter().enumerate() {
+            occurrences.entry(value).or_default().push(index);
+        }
+
+        let mut duplicates = occurrences
+            .into_iter()
+            .filter(|(_value, indices)| indices.len() > 1)
+            .collect::<Vec<(Value, Vec<usize>)>>();
+        duplicates.sort_by_key(|(_value, indices)| indices.first().copied());
+
+        Self(duplicates)
+    }
+}
+
+impl<Value> Duplicates<Value> {
+    /// Marks the index of the first occurrence of each duplicate among all `length` indices
+    fn first_occurrences(&self, length: usize) -> Vec<bool> {
+        let mut is_first_occurrence = vec![false; length];
+
+        for (_value, indices) in &self.0 {
+            if let Some(flag) = indices
+                .first()
+                .and_then(|first| is_first_occurrence.get_mut(*first))
+            {
+                *flag = true;
+            }
+        }
+
+        is_first_occurrence
+    }
+}
+
+/// The items whose index is marked
+fn keep_marked<Item>(items: Vec<Item>, marked: Vec<bool>) -> Vec<Item> {
+    items
+        .into_iter()
+        .zip(marked)
+        .filter(|(_item, is_marked)| *is_marked)
+        .map(|(item, _is_marked)| item)
+        .collect()
+}
+
+impl<Value: Debug> Debug for Duplicates<Value> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let lines = self
+            .0
+            .iter()
+            .map(|(value, indices)| format!("{value:?} at the indices {indices:?}"))
+            .collect::<Vec<String>>();
+
+        write!(f, "{}", lines.join("\n  "))
+    }
+}
+
+/// Numbers that can be converted to [`f64`] to compute the [`average`](IteratorAssertion::average) of an Iterable
+///
+/// Implemented for all primitive integer and floating point types.
+/// Integers with a magnitude above 2<sup>53</sup> are rounded to the nearest [`f64`].
+///
+/// This trait is sealed and cannot be implemented outside Smoothy.
+pub trait IntoF64: private::Sealed {
+    /// The number as the nearest [`f64`]
+    fn into_f64(self) -> f64;
+}
+
+macro_rules! impl_into_f64 {
+    (lossless: $($lossless:ty),*; lossy: $($lossy:ty),* $(,)?) => {
+        $(
+            impl private::Sealed for $lossless {}
+
+            impl IntoF64 for $lossless {
+                fn into_f64(self) -> f64 {
+                    f64::from(self)
+                }
+            }
+        )*
+        $(
+            impl private::Sealed for $lossy {}
+
+            impl IntoF64 for $lossy {
+                #[allow(clippy::as_conversions, clippy::cast_precision_loss)]
+                fn into_f64(self) -> f64 {
+                    self as f64
+                }
+            }
+        )*
+    };
+}
+
+impl_into_f64!(
+    lossless: i8, i16, i32, u8, u16, u32, f32, f64;
+    lossy: i64, i128, isize, u64, u128, usize,
+);
+
+/// The index of the key which replaced all preceding candidates according to `replaces`
+///
+/// Fails when two keys cannot be compared. The keys may be the items themselves.
+#[track_caller]
+fn extreme_index<Item, Key>(
+    actual: &[Item],
+    keys: &[Key],
+    compared: &str,
+    aggregate: &str,
+    replaces: impl Fn(Ordering) -> bool,
+) -> usize
+where
+    Item: Debug,
+    Key: Debug + PartialOrd,
+{
+    let mut extreme_index = 0;
+    let mut incomparable = None;
+    for (index, key) in keys.iter().enumerate().skip(1) {
+        let Some(candidate) = keys.get(extreme_index) else {
+            break;
+        };
+
+        match key.partial_cmp(candidate) {
+            Some(ordering) if replaces(ordering) => extreme_index = index,
+            Some(_) => {}
+            None => {
+                incomparable = Some(Incomparable {
+                    first_index: extreme_index,
+                    first: candidate,
+                    second_index: index,
+                    second: key,
+                });
+                break;
+            }
+        }
+    }
+
+    if let Some(incomparable_pair) = incomparable {
+        implementation::assert_with_additional_info_no_expected(
+            false,
+            actual,
+            &format!("to contain only comparable {compared} to determine {aggregate}"),
+            "but found",
+            incomparable_pair,
+        );
+    }
+
+    extreme_index
+}
+
+/// Takes the item at the index which has to exist
+fn item_at<Item>(actual: Vec<Item>, index: usize) -> Asserter<Item> {
+    #[allow(clippy::unwrap_used)]
+    let value = actual.into_iter().nth(index).unwrap();
+
+    Asserter { value }
+}
+
+/// Two items or keys whose [`PartialOrd`] implementation does not define an order between them
+struct Incomparable<'a, Item> {
+    first_index: usize,
+    first: &'a Item,
+    second_index: usize,
+    second: &'a Item,
+}
+
+impl<Item: Debug> Debug for Incomparable<'_, Item> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{:?} at index {} which cannot be compared to {:?} at index {}",
+            self.first, self.first_index, self.second, self.second_index
+        )
+    }
+}
+
+/// Collects the Iterable and asserts that it contains at least one item to compute the aggregate from
+#[track_caller]
+fn non_empty<Iterable, Item>(iterable: Iterable, aggregate: &str) -> Vec<Item>
+where
+    Iterable: IntoIterator<Item = Item>,
+    Item: Debug,
+{
+    let actual = iterable.into_iter().collect::<Vec<Item>>();
+
+    implementation::assert_no_expected(
+        !actual.is_empty(),
+        &actual,
+        &format!("to contain at least one item to determine {aggregate}"),
+    );
+
+    actual
 }
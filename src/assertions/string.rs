@@ -1,7 +1,30 @@
-use crate::{implementation, private, Asserter};
+use crate::{
+    assertions::{
+        ansi::{self, AnsiStyle, Styles},
+        captures::Captures,
+    },
+    implementation::{self, Displayed, Escaped, Normalized},
+    private, Asserter,
+};
+use closest::{closest_match, Anchor};
+use glob::Syntax;
+use std::{
+    fmt::{self, Debug},
+    str::FromStr,
+};
+use template::Template;
+
+mod closest;
+mod glob;
+mod template;
 
 /// Specifies various assertions on [`String`]. Implemented on [`Asserter`]
 ///
+/// Failing [`contains`](StringAssertion::contains), [`starts_with`](StringAssertion::starts_with)
+/// and [`ends_with`](StringAssertion::ends_with) show the closest match by edit distance.
+/// So does [`equals`](crate::EqualityAssertion::equals) on strings if they are similar enough,
+/// otherwise it shows the diff of both strings.
+///
 /// This trait is sealed and cannot be implemented outside Smoothy.
 pub trait StringAssertion<StringLike>: private::Sealed
 where
@@ -56,49 +79,1797 @@ where
     /// When the value does not start with the pattern
     #[track_caller]
     fn starts_with(self, string: impl AsRef<str>) -> Asserter<StringLike>;
-}
 
-impl<StringLike> StringAssertion<StringLike> for Asserter<StringLike>
-where
-    StringLike: AsRef<str>,
-{
-    fn contains(self, expected: impl AsRef<str>) -> Self {
-        let actual = self.value.as_ref();
+    /// Asserts that the value ends with the pattern
+    ///
+    /// # Examples
+    /// ```
+    /// # use smoothy::prelude::*;
+    /// #
+    /// assert_that("Hello World").ends_with("World");
+    /// ```
+    ///
+    /// ```should_panic
+    /// # use smoothy::prelude::*;
+    /// #
+    /// assert_that("Hello World\n").ends_with("World");
+    /// ```
+    ///
+    /// # Panics
+    /// When the value does not end with the pattern
+    #[track_caller]
+    fn ends_with(self, string: impl AsRef<str>) -> Asserter<StringLike>;
 
-        implementation::assert(
-            actual.contains(expected.as_ref()),
-            actual,
-            "to contain",
-            expected.as_ref(),
-        );
+    /// Asserts that the value is empty
+    ///
+    /// # Examples
+    /// ```
+    /// # use smoothy::prelude::*;
+    /// #
+    /// assert_that("").is_empty();
+    /// ```
+    ///
+    /// ```should_panic
+    /// # use smoothy::prelude::*;
+    /// #
+    /// assert_that(" ").is_empty();
+    /// ```
+    ///
+    /// # Panics
+    /// When the value is not empty
+    #[track_caller]
+    #[allow(clippy::wrong_self_convention)]
+    fn is_empty(self) -> Asserter<StringLike>;
 
-        self
-    }
+    /// Asserts that the value is not empty
+    ///
+    /// Whitespace counts as content. Use [`is_blank`](StringAssertion::is_blank) to assert that there is only whitespace.
+    ///
+    /// # Examples
+    /// ```
+    /// # use smoothy::prelude::*;
+    /// #
+    /// assert_that(" ").is_not_empty();
+    /// ```
+    ///
+    /// ```should_panic
+    /// # use smoothy::prelude::*;
+    /// #
+    /// assert_that("").is_not_empty();
+    /// ```
+    ///
+    /// # Panics
+    /// When the value is empty
+    #[track_caller]
+    #[allow(clippy::wrong_self_convention)]
+    fn is_not_empty(self) -> Asserter<StringLike>;
 
-    #[cfg(feature = "regex")]
-    fn matches(self, regex: &regex::Regex) -> Self {
-        let actual = self.value.as_ref();
+    /// Asserts that the value is empty or consists of whitespace only
+    ///
+    /// # Examples
+    /// ```
+    /// # use smoothy::prelude::*;
+    /// #
+    /// assert_that(" \t\r\n").is_blank();
+    /// assert_that("").is_blank();
+    /// ```
+    ///
+    /// ```should_panic
+    /// # use smoothy::prelude::*;
+    /// #
+    /// assert_that(" \u{200b} ").is_blank();
+    /// ```
+    ///
+    /// # Panics
+    /// When the value contains a character that is not whitespace
+    #[track_caller]
+    #[allow(clippy::wrong_self_convention)]
+    fn is_blank(self) -> Asserter<StringLike>;
 
-        implementation::assert(
-            regex.is_match(actual),
-            actual,
-            "to be matched by",
-            regex.to_string(),
-        );
+    /// Asserts that the value consists of the expected number of characters
+    ///
+    /// Characters are counted as [`char`]s i.e. unicode scalar values.
+    ///
+    /// # Examples
+    /// ```
+    /// # use smoothy::prelude::*;
+    /// #
+    /// assert_that("Grüße").has_length(5);
+    /// ```
+    ///
+    /// ```should_panic
+    /// # use smoothy::prelude::*;
+    /// #
+    /// assert_that("Grüße").has_length(7);
+    /// ```
+    ///
+    /// # Panics
+    /// When the number of characters differs
+    #[track_caller]
+    fn has_length(self, expected: usize) -> Asserter<StringLike>;
 
-        self
-    }
+    /// Asserts that the value consists of the expected number of bytes in UTF-8
+    ///
+    /// # Examples
+    /// ```
+    /// # use smoothy::prelude::*;
+    /// #
+    /// assert_that("Grüße").has_byte_length(7);
+    /// ```
+    ///
+    /// ```should_panic
+    /// # use smoothy::prelude::*;
+    /// #
+    /// assert_that("Grüße").has_byte_length(5);
+    /// ```
+    ///
+    /// # Panics
+    /// When the number of bytes differs
+    #[track_caller]
+    fn has_byte_length(self, expected: usize) -> Asserter<StringLike>;
 
-    fn starts_with(self, expected: impl AsRef<str>) -> Self {
-        let actual = self.value.as_ref();
+    /// Convenience function for getting the length of the value in characters
+    ///
+    /// Measures the same as [`has_length`](StringAssertion::has_length) and [`char_count`](StringAssertion::char_count).
+    /// Use [`byte_length`](StringAssertion::byte_length) for the number of bytes.
+    ///
+    /// # Examples
+    /// ```
+    /// # use smoothy::prelude::*;
+    /// #
+    /// assert_that("Grüße").length().is(5);
+    /// ```
+    #[track_caller]
+    #[must_use = "Accessing the length of the value does not assert anything"]
+    fn length(self) -> Asserter<usize>;
 
-        implementation::assert(
-            actual.starts_with(expected.as_ref()),
-            actual,
-            "to start with",
-            expected.as_ref(),
-        );
+    /// Convenience function for getting the number of bytes of the value in UTF-8 like [`str::len`]
+    ///
+    /// Measures the same as [`has_byte_length`](StringAssertion::has_byte_length).
+    ///
+    /// # Examples
+    /// ```
+    /// # use smoothy::prelude::*;
+    /// #
+    /// assert_that("Grüße").byte_length().is(7);
+    /// ```
+    #[track_caller]
+    #[must_use = "Accessing the length of the value does not assert anything"]
+    fn byte_length(self) -> Asserter<usize>;
 
-        self
-    }
+    /// Convenience function for getting the number of characters of the value
+    ///
+    /// Characters are counted as [`char`]s i.e. unicode scalar values.
+    /// Measures the same as [`has_length`](StringAssertion::has_length).
+    ///
+    /// # Examples
+    /// ```
+    /// # use smoothy::prelude::*;
+    /// #
+    /// assert_that("Grüße").char_count().is(5);
+    /// ```
+    #[track_caller]
+    #[must_use = "Accessing the number of characters of the value does not assert anything"]
+    fn char_count(self) -> Asserter<usize>;
+
+    /// Asserts that the value equals the expected one ignoring case
+    ///
+    /// Both are compared after unicode case folding, so e.g. `ß` and `SS` are considered equal.
+    ///
+    /// # Examples
+    /// ```
+    /// # use smoothy::prelude::*;
+    /// #
+    /// assert_that("Content-Type").equals_ignoring_case("content-type");
+    /// assert_that("Straße").equals_ignoring_case("STRASSE");
+    /// ```
+    ///
+    /// ```should_panic
+    /// # use smoothy::prelude::*;
+    /// #
+    /// assert_that("Content-Type").equals_ignoring_case("content-length");
+    /// ```
+    ///
+    /// # Panics
+    /// When the case-folded value differs from the case-folded expected one
+    #[track_caller]
+    fn equals_ignoring_case(self, expected: impl AsRef<str>) -> Asserter<StringLike>;
+
+    /// Asserts that the value contains the pattern ignoring case
+    ///
+    /// Both are compared after unicode case folding.
+    ///
+    /// # Examples
+    /// ```
+    /// # use smoothy::prelude::*;
+    /// #
+    /// assert_that("SELECT * FROM users").contains_ignoring_case("from");
+    /// ```
+    ///
+    /// ```should_panic
+    /// # use smoothy::prelude::*;
+    /// #
+    /// assert_that("SELECT * FROM users").contains_ignoring_case("where");
+    /// ```
+    ///
+    /// # Panics
+    /// When the case-folded value does not contain the case-folded pattern
+    #[track_caller]
+    fn contains_ignoring_case(self, string: impl AsRef<str>) -> Asserter<StringLike>;
+
+    /// Asserts that the value starts with the pattern ignoring case
+    ///
+    /// Both are compared after unicode case folding.
+    ///
+    /// # Examples
+    /// ```
+    /// # use smoothy::prelude::*;
+    /// #
+    /// assert_that("SELECT * FROM users").starts_with_ignoring_case("select");
+    /// ```
+    ///
+    /// ```should_panic
+    /// # use smoothy::prelude::*;
+    /// #
+    /// assert_that("SELECT * FROM users").starts_with_ignoring_case("insert");
+    /// ```
+    ///
+    /// # Panics
+    /// When the case-folded value does not start with the case-folded pattern
+    #[track_caller]
+    fn starts_with_ignoring_case(self, string: impl AsRef<str>) -> Asserter<StringLike>;
+
+    /// Asserts that the value equals the expected one after unicode normalization
+    ///
+    /// Both are compared in their canonical composition (NFC), so precomposed and decomposed characters are considered equal.
+    ///
+    /// # Examples
+    /// ```
+    /// # use smoothy::prelude::*;
+    /// #
+    /// assert_that("caf\u{e9}").equals_normalized("cafe\u{301}");
+    /// ```
+    ///
+    /// ```should_panic
+    /// # use smoothy::prelude::*;
+    /// #
+    /// assert_that("caf\u{e9}").equals_normalized("cafe");
+    /// ```
+    ///
+    /// # Panics
+    /// When the normalized value differs from the normalized expected one
+    #[cfg(feature = "unicode-normalization")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "unicode-normalization")))]
+    #[track_caller]
+    fn equals_normalized(self, expected: impl AsRef<str>) -> Asserter<StringLike>;
+
+    /// Asserts that the value equals the expected one after unicode normalization into the canonical decomposition
+    ///
+    /// Both are compared in their canonical decomposition (NFD), so precomposed and decomposed characters are considered equal.
+    /// In contrast to [`equals_normalized`](StringAssertion::equals_normalized) the failure shows the decomposed forms
+    /// in which combining characters like accents are separate.
+    ///
+    /// # Examples
+    /// ```
+    /// # use smoothy::prelude::*;
+    /// #
+    /// assert_that("cafe\u{301}").equals_normalized_nfd("caf\u{e9}");
+    /// ```
+    ///
+    /// ```should_panic
+    /// # use smoothy::prelude::*;
+    /// #
+    /// assert_that("caf\u{e9}").equals_normalized_nfd("cafe");
+    /// ```
+    ///
+    /// # Panics
+    /// When the decomposed value differs from the decomposed expected one
+    #[cfg(feature = "unicode-normalization")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "unicode-normalization")))]
+    #[track_caller]
+    fn equals_normalized_nfd(self, expected: impl AsRef<str>) -> Asserter<StringLike>;
+
+    /// Asserts that the value equals the expected one when all runs of whitespace are collapsed into a single space
+    ///
+    /// Leading and trailing whitespace is ignored.
+    ///
+    /// # Examples
+    /// ```
+    /// # use smoothy::prelude::*;
+    /// #
+    /// assert_that("SELECT *\n  FROM users\n  WHERE id = 1")
+    ///     .equals_ignoring_whitespace("SELECT * FROM users WHERE id = 1");
+    /// ```
+    ///
+    /// ```should_panic
+    /// # use smoothy::prelude::*;
+    /// #
+    /// assert_that("SELECT *\n  FROM users").equals_ignoring_whitespace("SELECT * FROMusers");
+    /// ```
+    ///
+    /// # Panics
+    /// When the values differ after collapsing whitespace. The diff is shown on the collapsed values.
+    #[track_caller]
+    fn equals_ignoring_whitespace(self, expected: impl AsRef<str>) -> Asserter<StringLike>;
+
+    /// Asserts that the value equals the expected one when all whitespace is removed
+    ///
+    /// # Examples
+    /// ```
+    /// # use smoothy::prelude::*;
+    /// #
+    /// assert_that("<ul>\n  <li>Item</li>\n</ul>")
+    ///     .equals_ignoring_all_whitespace("<ul><li>Item</li></ul>");
+    /// ```
+    ///
+    /// ```should_panic
+    /// # use smoothy::prelude::*;
+    /// #
+    /// assert_that("<ul>\n  <li>Item</li>\n</ul>").equals_ignoring_all_whitespace("<ul></ul>");
+    /// ```
+    ///
+    /// # Panics
+    /// When the values differ after removing all whitespace. The diff is shown on the values without whitespace.
+    #[track_caller]
+    fn equals_ignoring_all_whitespace(self, expected: impl AsRef<str>) -> Asserter<StringLike>;
+
+    /// Asserts that the value equals the expected one when Windows (`\r\n`) and classic Mac (`\r`) line endings are replaced by `\n`
+    ///
+    /// # Examples
+    /// ```
+    /// # use smoothy::prelude::*;
+    /// #
+    /// assert_that("first\r\nsecond\r\n").equals_ignoring_line_endings("first\nsecond\n");
+    /// ```
+    ///
+    /// ```should_panic
+    /// # use smoothy::prelude::*;
+    /// #
+    /// assert_that("first\r\nsecond").equals_ignoring_line_endings("first\nsecond\n");
+    /// ```
+    ///
+    /// # Panics
+    /// When the values differ after normalizing line endings. The diff is shown on the normalized values.
+    #[track_caller]
+    fn equals_ignoring_line_endings(self, expected: impl AsRef<str>) -> Asserter<StringLike>;
+
+    /// Asserts that the value equals the expected one when the common indentation is removed from both
+    ///
+    /// Lines consisting only of whitespace are ignored when determining the common indentation and are treated as empty.
+    ///
+    /// # Examples
+    /// ```
+    /// # use smoothy::prelude::*;
+    /// #
+    /// let generated = "    fn main() {\n        println!();\n    }";
+    ///
+    /// assert_that(generated).equals_ignoring_indentation("fn main() {\n    println!();\n}");
+    /// ```
+    ///
+    /// ```should_panic
+    /// # use smoothy::prelude::*;
+    /// #
+    /// let generated = "    fn main() {\n    println!();\n    }";
+    ///
+    /// assert_that(generated).equals_ignoring_indentation("fn main() {\n    println!();\n}");
+    /// ```
+    ///
+    /// # Panics
+    /// When the values differ after removing their common indentation. The diff is shown on the dedented values.
+    #[track_caller]
+    fn equals_ignoring_indentation(self, expected: impl AsRef<str>) -> Asserter<StringLike>;
+
+    /// Convenience function for splitting the value into its lines
+    ///
+    /// Lines are split like [`str::lines`], so line endings and a trailing line break are not part of the lines.
+    ///
+    /// # Examples
+    /// ```
+    /// # use smoothy::prelude::*;
+    /// #
+    /// assert_that("first\r\nsecond\n")
+    ///     .lines()
+    ///     .contains_only(["first", "second"]);
+    /// ```
+    #[track_caller]
+    #[must_use = "Transforming the asserted value does not assert anything"]
+    fn lines(self) -> Asserter<Vec<String>>;
+
+    /// Asserts that the value consists of the expected number of lines
+    ///
+    /// Lines are counted like [`str::lines`], so a trailing line break does not start a new line.
+    ///
+    /// # Examples
+    /// ```
+    /// # use smoothy::prelude::*;
+    /// #
+    /// assert_that("first\nsecond\n").has_line_count(2);
+    /// ```
+    ///
+    /// ```should_panic
+    /// # use smoothy::prelude::*;
+    /// #
+    /// assert_that("first\nsecond\n").has_line_count(3);
+    /// ```
+    ///
+    /// # Panics
+    /// When the number of lines differs
+    #[track_caller]
+    fn has_line_count(self, expected: usize) -> Asserter<StringLike>;
+
+    /// Asserts that one of the lines of the value equals the expected line
+    ///
+    /// # Examples
+    /// ```
+    /// # use smoothy::prelude::*;
+    /// #
+    /// assert_that("Compiling smoothy\nFinished release").contains_line("Finished release");
+    /// ```
+    ///
+    /// ```should_panic
+    /// # use smoothy::prelude::*;
+    /// #
+    /// assert_that("Compiling smoothy\nFinished release").contains_line("Finished");
+    /// ```
+    ///
+    /// # Panics
+    /// When no line equals the expected line
+    #[track_caller]
+    fn contains_line(self, line: impl AsRef<str>) -> Asserter<StringLike>;
+
+    /// Asserts that the value equals the expected one line by line
+    ///
+    /// Line endings and a trailing line break are ignored. On failure a unified diff of the lines with line numbers is shown.
+    ///
+    /// # Examples
+    /// ```
+    /// # use smoothy::prelude::*;
+    /// #
+    /// assert_that("first\r\nsecond\r\n").equals_lines("first\nsecond");
+    /// ```
+    ///
+    /// ```should_panic
+    /// # use smoothy::prelude::*;
+    /// #
+    /// assert_that("first\nsecond\nthird").equals_lines("first\nother\nthird");
+    /// ```
+    ///
+    /// # Panics
+    /// When at least one line differs
+    #[track_caller]
+    fn equals_lines(self, expected: impl AsRef<str>) -> Asserter<StringLike>;
+
+    /// Convenience function for splitting the value at each occurrence of the separator
+    ///
+    /// # Examples
+    /// ```
+    /// # use smoothy::prelude::*;
+    /// #
+    /// assert_that("id,name,email")
+    ///     .split(",")
+    ///     .contains_only(["id", "name", "email"]);
+    /// ```
+    #[track_caller]
+    #[must_use = "Transforming the asserted value does not assert anything"]
+    fn split(self, separator: impl AsRef<str>) -> Asserter<Vec<String>>;
+
+    /// Convenience function for splitting the value into its words separated by any amount of whitespace
+    ///
+    /// # Examples
+    /// ```
+    /// # use smoothy::prelude::*;
+    /// #
+    /// assert_that("  Hello \t World\n")
+    ///     .split_whitespace()
+    ///     .contains_only(["Hello", "World"]);
+    /// ```
+    #[track_caller]
+    #[must_use = "Transforming the asserted value does not assert anything"]
+    fn split_whitespace(self) -> Asserter<Vec<String>>;
+
+    /// Asserts that the value is matched by the pattern which is compiled to a [`Regex`](regex::Regex)
+    ///
+    /// # Examples
+    /// ```
+    /// # use smoothy::prelude::*;
+    /// #
+    /// assert_that("I categorically deny having triskaidekaphobia.").matches_pattern(r"\b\w{13}\b");
+    /// ```
+    ///
+    /// ```should_panic
+    /// # use smoothy::prelude::*;
+    /// #
+    /// assert_that("I categorically deny having triskaidekaphobia.").matches_pattern(r"\b\w{13");
+    /// ```
+    ///
+    /// # Panics
+    /// When the pattern is no valid regex or the value does not match it
+    #[cfg(feature = "regex")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "regex")))]
+    #[track_caller]
+    #[allow(clippy::wrong_self_convention)]
+    fn matches_pattern(self, pattern: impl AsRef<str>) -> Asserter<StringLike>;
+
+    /// Asserts that the value is not matched by the regex
+    ///
+    /// # Examples
+    /// ```
+    /// # use smoothy::prelude::*;
+    /// # use regex::Regex;
+    /// #
+    /// assert_that("Finished without errors").does_not_match(&Regex::new(r"(?i)\berror\b").unwrap());
+    /// ```
+    ///
+    /// ```should_panic
+    /// # use smoothy::prelude::*;
+    /// # use regex::Regex;
+    /// #
+    /// assert_that("Finished with 1 error").does_not_match(&Regex::new(r"(?i)\berror\b").unwrap());
+    /// ```
+    ///
+    /// # Panics
+    /// When the value is matched by the regex
+    #[cfg(feature = "regex")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "regex")))]
+    #[track_caller]
+    fn does_not_match(self, regex: &regex::Regex) -> Asserter<StringLike>;
+
+    /// Asserts that the whole value is matched by the regex as if it was anchored with `^` and `$`
+    ///
+    /// Options set via [`RegexBuilder`](regex::RegexBuilder) are not taken into account. Use inline flags like `(?i)` instead.
+    ///
+    /// # Examples
+    /// ```
+    /// # use smoothy::prelude::*;
+    /// # use regex::Regex;
+    /// #
+    /// assert_that("2026-10-19").is_fully_matched_by(&Regex::new(r"\d{4}-\d{2}-\d{2}").unwrap());
+    /// ```
+    ///
+    /// ```should_panic
+    /// # use smoothy::prelude::*;
+    /// # use regex::Regex;
+    /// #
+    /// assert_that("Date: 2026-10-19").is_fully_matched_by(&Regex::new(r"\d{4}-\d{2}-\d{2}").unwrap());
+    /// ```
+    ///
+    /// # Panics
+    /// When the regex does not match the whole value
+    #[cfg(feature = "regex")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "regex")))]
+    #[track_caller]
+    #[allow(clippy::wrong_self_convention)]
+    fn is_fully_matched_by(self, regex: &regex::Regex) -> Asserter<StringLike>;
+
+    /// Convenience function for getting all non-overlapping matches of the regex in the value
+    ///
+    /// # Examples
+    /// ```
+    /// # use smoothy::prelude::*;
+    /// # use regex::Regex;
+    /// #
+    /// assert_that("user-1, user-22 and user-333")
+    ///     .find_all(&Regex::new(r"user-\d+").unwrap())
+    ///     .contains_only(["user-1", "user-22", "user-333"]);
+    /// ```
+    #[cfg(feature = "regex")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "regex")))]
+    #[track_caller]
+    #[must_use = "Transforming the asserted value does not assert anything"]
+    fn find_all(self, regex: &regex::Regex) -> Asserter<Vec<String>>;
+
+    /// Asserts that the value is matched by the regex and converts it to the groups captured by the first match
+    ///
+    /// The groups can be accessed by their position or their name (see [`CapturesAssertion`](crate::CapturesAssertion)).
+    ///
+    /// # Examples
+    /// ```
+    /// # use smoothy::prelude::*;
+    /// # use regex::Regex;
+    /// #
+    /// let regex = Regex::new(r"(?<user>\w+) logged in from (?<ip>[\d.]+)").unwrap();
+    ///
+    /// assert_that("alice logged in from 10.0.0.1")
+    ///     .captures(&regex)
+    ///     .name("ip")
+    ///     .equals("10.0.0.1");
+    /// ```
+    ///
+    /// ```should_panic
+    /// # use smoothy::prelude::*;
+    /// # use regex::Regex;
+    /// #
+    /// let regex = Regex::new(r"(?<user>\w+) logged in from (?<ip>[\d.]+)").unwrap();
+    ///
+    /// let _captures = assert_that("alice logged out").captures(&regex);
+    /// ```
+    ///
+    /// # Panics
+    /// When the value is not matched by the regex
+    #[cfg(feature = "regex")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "regex")))]
+    #[track_caller]
+    #[must_use = "Transforming the asserted value only asserts that the regex matches"]
+    fn captures(self, regex: &regex::Regex) -> Asserter<Captures>;
+
+    /// Asserts that the value contains the pattern exactly the expected number of times
+    ///
+    /// Occurrences are counted without overlapping like [`str::matches`].
+    ///
+    /// # Examples
+    /// ```
+    /// # use smoothy::prelude::*;
+    /// #
+    /// assert_that("retrying...\nretrying...\nconnected").contains_times("retrying", 2);
+    /// ```
+    ///
+    /// ```should_panic
+    /// # use smoothy::prelude::*;
+    /// #
+    /// assert_that("retrying...\nretrying...\nconnected").contains_times("retrying", 1);
+    /// ```
+    ///
+    /// # Panics
+    /// When the pattern occurs more or less often
+    #[track_caller]
+    fn contains_times(self, pattern: impl AsRef<str>, expected: usize) -> Asserter<StringLike>;
+
+    /// Convenience function for counting the non-overlapping occurrences of the pattern in the value
+    ///
+    /// # Examples
+    /// ```
+    /// # use smoothy::prelude::*;
+    /// #
+    /// assert_that("retrying...\nretrying...\nconnected")
+    ///     .occurrences("retrying")
+    ///     .is(2);
+    /// ```
+    #[track_caller]
+    #[must_use = "Counting the occurrences of the pattern does not assert anything"]
+    fn occurrences(self, pattern: impl AsRef<str>) -> Asserter<usize>;
+
+    /// Asserts that the value contains each of the patterns at least once in any order
+    ///
+    /// # Examples
+    /// ```
+    /// # use smoothy::prelude::*;
+    /// #
+    /// assert_that("connected to db, listening on :8080").contains_all_of(["listening", "connected"]);
+    /// ```
+    ///
+    /// ```should_panic
+    /// # use smoothy::prelude::*;
+    /// #
+    /// assert_that("connected to db").contains_all_of(["listening", "connected"]);
+    /// ```
+    ///
+    /// # Panics
+    /// When at least one of the patterns is not contained
+    #[track_caller]
+    fn contains_all_of(
+        self,
+        patterns: impl IntoIterator<Item = impl AsRef<str>>,
+    ) -> Asserter<StringLike>;
+
+    /// Asserts that the value contains at least one of the patterns
+    ///
+    /// # Examples
+    /// ```
+    /// # use smoothy::prelude::*;
+    /// #
+    /// assert_that("WARN disk almost full").contains_any_of(["WARN", "ERROR"]);
+    /// ```
+    ///
+    /// ```should_panic
+    /// # use smoothy::prelude::*;
+    /// #
+    /// assert_that("INFO started").contains_any_of(["WARN", "ERROR"]);
+    /// ```
+    ///
+    /// # Panics
+    /// When none of the patterns is contained
+    #[track_caller]
+    fn contains_any_of(
+        self,
+        patterns: impl IntoIterator<Item = impl AsRef<str>>,
+    ) -> Asserter<StringLike>;
+
+    /// Asserts that the value contains the patterns one after another in the given order
+    ///
+    /// Each pattern has to occur after the end of the occurrence of the previous one. Other content may lie in between.
+    ///
+    /// # Examples
+    /// ```
+    /// # use smoothy::prelude::*;
+    /// #
+    /// assert_that("starting\nconnected\nlistening").contains_in_order(["starting", "listening"]);
+    /// ```
+    ///
+    /// ```should_panic
+    /// # use smoothy::prelude::*;
+    /// #
+    /// assert_that("starting\nlistening\nconnected").contains_in_order(["connected", "listening"]);
+    /// ```
+    ///
+    /// # Panics
+    /// When a pattern is missing or only occurs before the previous one
+    #[track_caller]
+    fn contains_in_order(
+        self,
+        patterns: impl IntoIterator<Item = impl AsRef<str>>,
+    ) -> Asserter<StringLike>;
+
+    /// Asserts that the value can be parsed via [`FromStr`] and converts it for further assertions
+    ///
+    /// # Examples
+    /// ```
+    /// # use smoothy::prelude::*;
+    /// # use std::net::Ipv4Addr;
+    /// #
+    /// assert_that("42").parsed::<u16>().is(42);
+    /// assert_that("127.0.0.1")
+    ///     .parsed::<Ipv4Addr>()
+    ///     .is(Ipv4Addr::LOCALHOST);
+    /// ```
+    ///
+    /// ```should_panic
+    /// # use smoothy::prelude::*;
+    /// #
+    /// let _parsed = assert_that("-1").parsed::<u16>();
+    /// ```
+    ///
+    /// # Panics
+    /// When parsing the value fails
+    #[track_caller]
+    #[must_use = "Transforming the asserted value only asserts that it can be parsed"]
+    fn parsed<Parsed>(self) -> Asserter<Parsed>
+    where
+        Parsed: FromStr,
+        Parsed::Err: Debug;
+
+    /// Asserts that the whole value matches the glob pattern
+    ///
+    /// The pattern supports
+    /// - `*` matching any number of characters
+    /// - `?` matching exactly one character
+    /// - character classes like `[abc]`, `[a-z]` or the negated `[!a-z]` and `[^a-z]`
+    /// - `\` escaping the following character
+    ///
+    /// In contrast to file system globs `*` also matches `/`.
+    ///
+    /// # Examples
+    /// ```
+    /// # use smoothy::prelude::*;
+    /// #
+    /// assert_that("user-alice-01.log").matches_glob("user-*-[0-9][0-9].log");
+    /// ```
+    ///
+    /// ```should_panic
+    /// # use smoothy::prelude::*;
+    /// #
+    /// assert_that("user-alice-01.txt").matches_glob("user-*-[0-9][0-9].log");
+    /// ```
+    ///
+    /// # Panics
+    /// When the pattern is invalid or the value does not match it
+    #[track_caller]
+    fn matches_glob(self, pattern: impl AsRef<str>) -> Asserter<StringLike>;
+
+    /// Asserts that the whole value matches the wildcard pattern
+    ///
+    /// The pattern supports `*` matching any number of characters, `?` matching exactly one character
+    /// and `\` escaping the following character. All other characters are matched literally.
+    ///
+    /// # Examples
+    /// ```
+    /// # use smoothy::prelude::*;
+    /// #
+    /// assert_that("user-alice-01.log").matches_wildcard("user-*-??.log");
+    /// ```
+    ///
+    /// ```should_panic
+    /// # use smoothy::prelude::*;
+    /// #
+    /// assert_that("user-alice-1.log").matches_wildcard("user-*-??.log");
+    /// ```
+    ///
+    /// # Panics
+    /// When the pattern is invalid or the value does not match it
+    #[track_caller]
+    fn matches_wildcard(self, pattern: impl AsRef<str>) -> Asserter<StringLike>;
+
+    /// Asserts that the whole value matches the template and converts it to the values of the placeholders
+    ///
+    /// The template consists of literal text and placeholders
+    /// - `{name}` matching any text which is captured under its name
+    /// - `{*}` matching any text which is ignored
+    ///
+    /// Literal braces are escaped by doubling them (`{{` and `}}`).
+    /// Placeholders match as little text as possible except for the last one which extends up to the final literal text.
+    ///
+    /// The values of the placeholders can be accessed by their name or all at once as a map
+    /// (see [`CapturesAssertion`](crate::CapturesAssertion)).
+    ///
+    /// # Examples
+    /// ```
+    /// # use smoothy::prelude::*;
+    /// #
+    /// assert_that("Created order 7f3a9c at 2026-10-19T13:37:00Z")
+    ///     .matches_template("Created order {id} at {*}")
+    ///     .name("id")
+    ///     .equals("7f3a9c");
+    /// ```
+    ///
+    /// ```should_panic
+    /// # use smoothy::prelude::*;
+    /// #
+    /// let _captures = assert_that("Deleted order 7f3a9c").matches_template("Created order {id}");
+    /// ```
+    ///
+    /// # Panics
+    /// When the template is invalid or the value does not match it
+    #[track_caller]
+    #[must_use = "Transforming the asserted value only asserts that the template matches"]
+    fn matches_template(self, template: impl AsRef<str>) -> Asserter<Captures>;
+
+    /// Convenience function for removing all ANSI escape sequences like colors or hyperlinks from the value
+    ///
+    /// This removes CSI sequences (e.g. `ESC[1;31m`), OSC sequences (e.g. `ESC]8;;<url>ESC\`), sequences with intermediate bytes (e.g. `ESC(B`) and other escape sequences.
+    ///
+    /// # Examples
+    /// ```
+    /// # use smoothy::prelude::*;
+    /// #
+    /// assert_that("\u{1b}[1;31merror\u{1b}[0m: file not found")
+    ///     .without_ansi()
+    ///     .equals("error: file not found");
+    /// ```
+    #[track_caller]
+    #[must_use = "Transforming the asserted value does not assert anything"]
+    fn without_ansi(self) -> Asserter<String>;
+
+    /// Asserts that the text is contained in the value after removing ANSI escape sequences and is rendered with the style
+    ///
+    /// At least one occurrence of the text has to have the style on all of its characters.
+    ///
+    /// # Examples
+    /// ```
+    /// # use smoothy::prelude::*;
+    /// # use smoothy::{AnsiColor, AnsiStyle};
+    /// #
+    /// assert_that("\u{1b}[1;31merror\u{1b}[0m: file not found")
+    ///     .has_ansi_style("error", AnsiStyle::Bold)
+    ///     .and()
+    ///     .has_ansi_style("error", AnsiStyle::Foreground(AnsiColor::Red));
+    /// ```
+    ///
+    /// ```should_panic
+    /// # use smoothy::prelude::*;
+    /// # use smoothy::AnsiStyle;
+    /// #
+    /// assert_that("\u{1b}[1;31merror\u{1b}[0m: file not found")
+    ///     .has_ansi_style("file", AnsiStyle::Bold);
+    /// ```
+    ///
+    /// # Panics
+    /// When the text is not contained or no occurrence of it is rendered with the style
+    #[track_caller]
+    fn has_ansi_style(self, text: impl AsRef<str>, style: AnsiStyle) -> Asserter<StringLike>;
+}
+
+impl<StringLike> StringAssertion<StringLike> for Asserter<StringLike>
+where
+    StringLike: AsRef<str>,
+{
+    fn contains(self, expected: impl AsRef<str>) -> Self {
+        let actual = self.value.as_ref();
+
+        implementation::assert_with_closest_match(
+            actual.contains(expected.as_ref()),
+            Escaped(actual),
+            "to contain",
+            Escaped(expected.as_ref()),
+            || closest_match(actual, expected.as_ref(), Anchor::Anywhere),
+        );
+
+        self
+    }
+
+    #[cfg(feature = "regex")]
+    fn matches(self, regex: &regex::Regex) -> Self {
+        let actual = self.value.as_ref();
+
+        implementation::assert(
+            regex.is_match(actual),
+            Escaped(actual),
+            "to be matched by",
+            regex.to_string(),
+        );
+
+        self
+    }
+
+    fn starts_with(self, expected: impl AsRef<str>) -> Self {
+        let actual = self.value.as_ref();
+
+        implementation::assert_with_closest_match(
+            actual.starts_with(expected.as_ref()),
+            Escaped(actual),
+            "to start with",
+            Escaped(expected.as_ref()),
+            || closest_match(actual, expected.as_ref(), Anchor::Start),
+        );
+
+        self
+    }
+
+    fn ends_with(self, expected: impl AsRef<str>) -> Self {
+        let actual = self.value.as_ref();
+
+        implementation::assert_with_closest_match(
+            actual.ends_with(expected.as_ref()),
+            Escaped(actual),
+            "to end with",
+            Escaped(expected.as_ref()),
+            || closest_match(actual, expected.as_ref(), Anchor::End),
+        );
+
+        self
+    }
+
+    fn is_empty(self) -> Self {
+        let actual = self.value.as_ref();
+
+        implementation::assert_no_expected(actual.is_empty(), Escaped(actual), "to be empty");
+
+        self
+    }
+
+    fn is_not_empty(self) -> Self {
+        let actual = self.value.as_ref();
+
+        implementation::assert_no_expected(!actual.is_empty(), Escaped(actual), "to not be empty");
+
+        self
+    }
+
+    fn is_blank(self) -> Self {
+        let actual = self.value.as_ref();
+        let non_whitespace = actual
+            .chars()
+            .filter(|character| !character.is_whitespace())
+            .collect::<String>();
+
+        implementation::assert_with_additional_info_no_expected(
+            non_whitespace.is_empty(),
+            Escaped(actual),
+            "to be blank",
+            "but contained the non-whitespace characters",
+            Escaped(&non_whitespace),
+        );
+
+        self
+    }
+
+    fn has_length(self, expected: usize) -> Self {
+        let actual = self.value.as_ref();
+        let char_count = actual.chars().count();
+
+        implementation::assert_with_additional_info(
+            char_count == expected,
+            Escaped(actual),
+            "to have a length in characters of",
+            expected,
+            "but had a length of",
+            char_count,
+        );
+
+        self
+    }
+
+    fn has_byte_length(self, expected: usize) -> Self {
+        let actual = self.value.as_ref();
+
+        implementation::assert_with_additional_info(
+            actual.len() == expected,
+            Escaped(actual),
+            "to have a length in bytes of",
+            expected,
+            "but had a length of",
+            actual.len(),
+        );
+
+        self
+    }
+
+    fn length(self) -> Asserter<usize> {
+        self.char_count()
+    }
+
+    fn byte_length(self) -> Asserter<usize> {
+        Asserter {
+            value: self.value.as_ref().len(),
+        }
+    }
+
+    fn char_count(self) -> Asserter<usize> {
+        Asserter {
+            value: self.value.as_ref().chars().count(),
+        }
+    }
+
+    fn equals_ignoring_case(self, expected: impl AsRef<str>) -> Self {
+        let actual = self.value.as_ref();
+        let folded_actual = fold_case(actual);
+        let folded_expected = fold_case(expected.as_ref());
+
+        implementation::assert_with_additional_info(
+            folded_actual == folded_expected,
+            Escaped(actual),
+            "to equal ignoring case",
+            Escaped(expected.as_ref()),
+            "but the case-folded forms were",
+            Normalized {
+                actual: Escaped(&folded_actual),
+                expected: Escaped(&folded_expected),
+            },
+        );
+
+        self
+    }
+
+    fn contains_ignoring_case(self, expected: impl AsRef<str>) -> Self {
+        let actual = self.value.as_ref();
+        let folded_actual = fold_case(actual);
+        let folded_expected = fold_case(expected.as_ref());
+
+        implementation::assert_with_additional_info(
+            folded_actual.contains(&folded_expected),
+            Escaped(actual),
+            "to contain ignoring case",
+            Escaped(expected.as_ref()),
+            "but the case-folded forms were",
+            Normalized {
+                actual: Escaped(&folded_actual),
+                expected: Escaped(&folded_expected),
+            },
+        );
+
+        self
+    }
+
+    fn starts_with_ignoring_case(self, expected: impl AsRef<str>) -> Self {
+        let actual = self.value.as_ref();
+        let folded_actual = fold_case(actual);
+        let folded_expected = fold_case(expected.as_ref());
+
+        implementation::assert_with_additional_info(
+            folded_actual.starts_with(&folded_expected),
+            Escaped(actual),
+            "to start with ignoring case",
+            Escaped(expected.as_ref()),
+            "but the case-folded forms were",
+            Normalized {
+                actual: Escaped(&folded_actual),
+                expected: Escaped(&folded_expected),
+            },
+        );
+
+        self
+    }
+
+    #[cfg(feature = "unicode-normalization")]
+    fn equals_normalized(self, expected: impl AsRef<str>) -> Self {
+        use unicode_normalization::UnicodeNormalization;
+
+        assert_equals_in_normal_form(self.value.as_ref(), expected.as_ref(), "NFC", |string| {
+            string.nfc().collect()
+        });
+
+        self
+    }
+
+    #[cfg(feature = "unicode-normalization")]
+    fn equals_normalized_nfd(self, expected: impl AsRef<str>) -> Self {
+        use unicode_normalization::UnicodeNormalization;
+
+        assert_equals_in_normal_form(self.value.as_ref(), expected.as_ref(), "NFD", |string| {
+            string.nfd().collect()
+        });
+
+        self
+    }
+
+    fn equals_ignoring_whitespace(self, expected: impl AsRef<str>) -> Self {
+        implementation::assert_equals_normalized(
+            &collapse_whitespace(self.value.as_ref()),
+            &collapse_whitespace(expected.as_ref()),
+            "ignoring whitespace",
+        );
+
+        self
+    }
+
+    fn equals_ignoring_all_whitespace(self, expected: impl AsRef<str>) -> Self {
+        implementation::assert_equals_normalized(
+            &remove_whitespace(self.value.as_ref()),
+            &remove_whitespace(expected.as_ref()),
+            "ignoring all whitespace",
+        );
+
+        self
+    }
+
+    fn equals_ignoring_line_endings(self, expected: impl AsRef<str>) -> Self {
+        implementation::assert_equals_normalized(
+            &normalize_line_endings(self.value.as_ref()),
+            &normalize_line_endings(expected.as_ref()),
+            "ignoring line endings",
+        );
+
+        self
+    }
+
+    fn equals_ignoring_indentation(self, expected: impl AsRef<str>) -> Self {
+        implementation::assert_equals_normalized(
+            &dedent(self.value.as_ref()),
+            &dedent(expected.as_ref()),
+            "ignoring indentation",
+        );
+
+        self
+    }
+
+    fn lines(self) -> Asserter<Vec<String>> {
+        Asserter {
+            value: self.value.as_ref().lines().map(String::from).collect(),
+        }
+    }
+
+    fn has_line_count(self, expected: usize) -> Self {
+        let lines = self
+            .value
+            .as_ref()
+            .lines()
+            .map(Escaped)
+            .collect::<Vec<Escaped>>();
+
+        implementation::assert_with_additional_info(
+            lines.len() == expected,
+            &lines,
+            "to have a line count of",
+            expected,
+            "but had a line count of",
+            lines.len(),
+        );
+
+        self
+    }
+
+    fn contains_line(self, expected: impl AsRef<str>) -> Self {
+        let lines = self.value.as_ref().lines().collect::<Vec<&str>>();
+
+        implementation::assert(
+            lines.contains(&expected.as_ref()),
+            lines.iter().copied().map(Escaped).collect::<Vec<Escaped>>(),
+            "to contain the line",
+            Escaped(expected.as_ref()),
+        );
+
+        self
+    }
+
+    fn equals_lines(self, expected: impl AsRef<str>) -> Self {
+        implementation::assert_equals_lines(self.value.as_ref(), expected.as_ref());
+
+        self
+    }
+
+    fn split(self, separator: impl AsRef<str>) -> Asserter<Vec<String>> {
+        Asserter {
+            value: self
+                .value
+                .as_ref()
+                .split(separator.as_ref())
+                .map(String::from)
+                .collect(),
+        }
+    }
+
+    fn split_whitespace(self) -> Asserter<Vec<String>> {
+        Asserter {
+            value: self
+                .value
+                .as_ref()
+                .split_whitespace()
+                .map(String::from)
+                .collect(),
+        }
+    }
+
+    #[cfg(feature = "regex")]
+    fn matches_pattern(self, pattern: impl AsRef<str>) -> Self {
+        let regex = compile(pattern.as_ref());
+        let actual = self.value.as_ref();
+
+        implementation::assert(
+            regex.is_match(actual),
+            Escaped(actual),
+            "to be matched by",
+            regex.to_string(),
+        );
+
+        self
+    }
+
+    #[cfg(feature = "regex")]
+    fn does_not_match(self, regex: &regex::Regex) -> Self {
+        let actual = self.value.as_ref();
+        let matches = regex
+            .find_iter(actual)
+            .map(|found| found.as_str())
+            .collect::<Vec<&str>>();
+
+        implementation::assert_with_additional_info(
+            matches.is_empty(),
+            Escaped(actual),
+            "to not be matched by",
+            regex.to_string(),
+            "but found the matches",
+            matches,
+        );
+
+        self
+    }
+
+    #[cfg(feature = "regex")]
+    fn is_fully_matched_by(self, regex: &regex::Regex) -> Self {
+        let anchored = compile(&format!(r"\A(?:{})\z", regex.as_str()));
+        let actual = self.value.as_ref();
+
+        implementation::assert_with_additional_info(
+            anchored.is_match(actual),
+            Escaped(actual),
+            "to be fully matched by",
+            regex.to_string(),
+            "but the first match was",
+            regex.find(actual).map(|found| found.as_str()),
+        );
+
+        self
+    }
+
+    #[cfg(feature = "regex")]
+    fn find_all(self, regex: &regex::Regex) -> Asserter<Vec<String>> {
+        Asserter {
+            value: regex
+                .find_iter(self.value.as_ref())
+                .map(|found| String::from(found.as_str()))
+                .collect(),
+        }
+    }
+
+    #[cfg(feature = "regex")]
+    fn captures(self, regex: &regex::Regex) -> Asserter<Captures> {
+        let actual = self.value.as_ref();
+        let maybe_captures = regex.captures(actual);
+
+        implementation::assert(
+            maybe_captures.is_some(),
+            Escaped(actual),
+            "to be matched by",
+            regex.to_string(),
+        );
+
+        #[allow(clippy::unwrap_used)]
+        let captures = maybe_captures.unwrap();
+
+        Asserter {
+            value: Captures::from_regex(regex, &captures),
+        }
+    }
+
+    fn contains_times(self, pattern: impl AsRef<str>, expected: usize) -> Self {
+        let actual = self.value.as_ref();
+        let offsets = actual
+            .match_indices(pattern.as_ref())
+            .map(|(offset, _)| offset)
+            .collect::<Vec<usize>>();
+
+        implementation::assert_with_additional_info(
+            offsets.len() == expected,
+            Escaped(actual),
+            &format!("to contain exactly {expected} occurrences of"),
+            Escaped(pattern.as_ref()),
+            &format!("but found {} at the byte offsets", offsets.len()),
+            offsets,
+        );
+
+        self
+    }
+
+    fn occurrences(self, pattern: impl AsRef<str>) -> Asserter<usize> {
+        Asserter {
+            value: self.value.as_ref().matches(pattern.as_ref()).count(),
+        }
+    }
+
+    fn contains_all_of(self, patterns: impl IntoIterator<Item = impl AsRef<str>>) -> Self {
+        let actual = self.value.as_ref();
+        let occurrences = patterns
+            .into_iter()
+            .map(|pattern| {
+                match actual.find(pattern.as_ref()) {
+                    Some(offset) => Occurrence::Found { pattern, offset },
+                    None => Occurrence::Missing { pattern },
+                }
+            })
+            .collect::<Vec<_>>();
+
+        implementation::assert_with_additional_info_no_expected(
+            occurrences
+                .iter()
+                .all(|occurrence| matches!(occurrence, Occurrence::Found { .. })),
+            Escaped(actual),
+            "to contain all of the patterns",
+            "but found",
+            Occurrences(occurrences),
+        );
+
+        self
+    }
+
+    fn contains_any_of(self, patterns: impl IntoIterator<Item = impl AsRef<str>>) -> Self {
+        let actual = self.value.as_ref();
+        let candidates = patterns.into_iter().collect::<Vec<_>>();
+
+        implementation::assert(
+            candidates
+                .iter()
+                .any(|pattern| actual.contains(pattern.as_ref())),
+            Escaped(actual),
+            "to contain any of",
+            candidates
+                .iter()
+                .map(|pattern| Escaped(pattern.as_ref()))
+                .collect::<Vec<Escaped>>(),
+        );
+
+        self
+    }
+
+    fn contains_in_order(self, patterns: impl IntoIterator<Item = impl AsRef<str>>) -> Self {
+        let actual = self.value.as_ref();
+        let mut search_start = 0;
+        let mut in_order = true;
+
+        let occurrences = patterns
+            .into_iter()
+            .map(|pattern| {
+                let remaining = actual.get(search_start..).unwrap_or_default();
+
+                if let Some(offset) = remaining.find(pattern.as_ref()) {
+                    let found_at = search_start + offset;
+                    search_start = found_at + pattern.as_ref().len();
+                    return Occurrence::Found {
+                        pattern,
+                        offset: found_at,
+                    };
+                }
+
+                in_order = false;
+                match actual.find(pattern.as_ref()) {
+                    Some(offset) => {
+                        Occurrence::Before {
+                            pattern,
+                            offset,
+                            expected_after: search_start,
+                        }
+                    }
+                    None => Occurrence::Missing { pattern },
+                }
+            })
+            .collect::<Vec<_>>();
+
+        implementation::assert_with_additional_info_no_expected(
+            in_order,
+            Escaped(actual),
+            "to contain the patterns in order",
+            "but found",
+            Occurrences(occurrences),
+        );
+
+        self
+    }
+
+    fn parsed<Parsed>(self) -> Asserter<Parsed>
+    where
+        Parsed: FromStr,
+        Parsed::Err: Debug,
+    {
+        let actual = self.value.as_ref();
+        let result = actual.parse::<Parsed>();
+
+        if let Err(error) = &result {
+            implementation::assert_with_additional_info_no_expected(
+                false,
+                Escaped(actual),
+                &format!("to be parsable as {}", std::any::type_name::<Parsed>()),
+                "but parsing failed with",
+                error,
+            );
+        }
+
+        #[allow(clippy::unwrap_used)]
+        let value = result.unwrap();
+
+        Asserter { value }
+    }
+
+    fn matches_glob(self, pattern: impl AsRef<str>) -> Self {
+        match_pattern(self.value.as_ref(), pattern.as_ref(), Syntax::Glob, "glob");
+
+        self
+    }
+
+    fn matches_wildcard(self, pattern: impl AsRef<str>) -> Self {
+        match_pattern(
+            self.value.as_ref(),
+            pattern.as_ref(),
+            Syntax::Wildcard,
+            "wildcard pattern",
+        );
+
+        self
+    }
+
+    fn matches_template(self, template: impl AsRef<str>) -> Asserter<Captures> {
+        let actual = self.value.as_ref();
+        let parsed = Template::parse(template.as_ref());
+
+        if let Err(error) = &parsed {
+            implementation::assert_with_additional_info_no_expected(
+                false,
+                Escaped(template.as_ref()),
+                "to be a valid template",
+                "but parsing failed with",
+                Displayed(error),
+            );
+        }
+
+        #[allow(clippy::unwrap_used)]
+        let result = parsed.unwrap().matches(actual);
+
+        if let Err(divergence) = &result {
+            implementation::assert_with_additional_info(
+                false,
+                Escaped(actual),
+                "to match the template",
+                Escaped(template.as_ref()),
+                "but the literal text diverged at",
+                divergence,
+            );
+        }
+
+        #[allow(clippy::unwrap_used)]
+        let placeholders = result.unwrap();
+
+        Asserter {
+            value: Captures::from_template(placeholders),
+        }
+    }
+
+    fn without_ansi(self) -> Asserter<String> {
+        Asserter {
+            value: ansi::render(self.value.as_ref()).text,
+        }
+    }
+
+    fn has_ansi_style(self, text: impl AsRef<str>, style: AnsiStyle) -> Self {
+        let actual = self.value.as_ref();
+        let rendered = ansi::render(actual);
+        let occurrences = rendered
+            .text
+            .match_indices(text.as_ref())
+            .map(|(offset, found)| {
+                let start = rendered
+                    .text
+                    .get(..offset)
+                    .unwrap_or_default()
+                    .chars()
+                    .count();
+                let styles = rendered
+                    .styles
+                    .iter()
+                    .skip(start)
+                    .take(found.chars().count())
+                    .cloned()
+                    .reduce(|shared, styles| shared.intersection(&styles))
+                    .unwrap_or_default();
+
+                StyledOccurrence { offset, styles }
+            })
+            .collect::<Vec<StyledOccurrence>>();
+
+        if occurrences.is_empty() {
+            implementation::assert_with_additional_info(
+                false,
+                Escaped(actual),
+                &format!("to render {:?} with the style", Escaped(text.as_ref())),
+                style,
+                "but the rendered text does not contain it",
+                Escaped(&rendered.text),
+            );
+        }
+
+        implementation::assert_with_additional_info(
+            occurrences
+                .iter()
+                .any(|occurrence| occurrence.styles.contains(style)),
+            Escaped(actual),
+            &format!("to render {:?} with the style", Escaped(text.as_ref())),
+            style,
+            "but found it rendered with the styles shared by all of its characters",
+            Occurrences(occurrences),
+        );
+
+        self
+    }
+}
+
+/// Fails [`equals`](crate::EqualityAssertion::equals) with the closest match if both values are strings
+///
+/// Values count as strings when their [`Debug`] implementation renders a single string literal
+/// like the one of [`String`] or `&str`. Returns without failing if the values are no strings
+/// or not similar enough for a hint, so the regular diff can be shown instead.
+#[track_caller]
+pub(super) fn assert_equals_with_closest_match<T: Debug>(actual: &T, expected: &T) {
+    let (Some(actual_string), Some(expected_string)) = (
+        unescape_debug(&format!("{actual:?}")),
+        unescape_debug(&format!("{expected:?}")),
+    ) else {
+        return;
+    };
+
+    if let Some(closest) = closest_match(&actual_string, &expected_string, Anchor::Whole) {
+        implementation::assert_with_closest_match(
+            false,
+            actual,
+            "to be equal to",
+            expected,
+            || Some(closest),
+        );
+    }
+}
+
+/// The content of a string literal as rendered by the [`Debug`] implementation of [`str`]
+fn unescape_debug(debug: &str) -> Option<String> {
+    let literal = debug.strip_prefix('"')?.strip_suffix('"')?;
+    let mut characters = literal.chars();
+    let mut unescaped = String::with_capacity(literal.len());
+
+    while let Some(character) = characters.next() {
+        let unescaped_character = match character {
+            '\\' => {
+                match characters.next()? {
+                    'n' => '\n',
+                    'r' => '\r',
+                    't' => '\t',
+                    '0' => '\0',
+                    'u' => {
+                        let code = characters
+                            .by_ref()
+                            .skip_while(|brace| *brace == '{')
+                            .take_while(|brace| *brace != '}')
+                            .collect::<String>();
+                        char::from_u32(u32::from_str_radix(&code, 16).ok()?)?
+                    }
+                    escaped @ ('\\' | '"' | '\'') => escaped,
+                    _ => return None,
+                }
+            }
+            // an unescaped quote means the value consists of more than one literal
+            '"' => return None,
+            literal_character => literal_character,
+        };
+        unescaped.push(unescaped_character);
+    }
+
+    Some(unescaped)
+}
+
+/// Fails if the value differs from the expected one in the normal form showing both normalized forms
+#[cfg(feature = "unicode-normalization")]
+#[track_caller]
+fn assert_equals_in_normal_form(
+    actual: &str,
+    expected: &str,
+    form: &str,
+    normalize: fn(&str) -> String,
+) {
+    let normalized_actual = normalize(actual);
+    let normalized_expected = normalize(expected);
+
+    implementation::assert_with_additional_info(
+        normalized_actual == normalized_expected,
+        Escaped(actual),
+        "to equal after normalization",
+        Escaped(expected),
+        &format!("but the normalized ({form}) forms were"),
+        Normalized {
+            actual: Escaped(&normalized_actual),
+            expected: Escaped(&normalized_expected),
+        },
+    );
+}
+
+/// Fails the assertion if the pattern is invalid or the whole value does not match it
+#[track_caller]
+fn match_pattern(actual: &str, pattern: &str, syntax: Syntax, kind: &str) {
+    let compiled = glob::Pattern::compile(pattern, syntax);
+
+    if let Err(error) = &compiled {
+        implementation::assert_with_additional_info_no_expected(
+            false,
+            Escaped(pattern),
+            &format!("to be a valid {kind}"),
+            "but parsing failed with",
+            Displayed(error),
+        );
+    }
+
+    #[allow(clippy::unwrap_used)]
+    if let Err(divergence) = compiled.unwrap().matches(actual) {
+        implementation::assert_with_additional_info(
+            false,
+            Escaped(actual),
+            &format!("to match the {kind}"),
+            Escaped(pattern),
+            "but the match diverged at",
+            divergence,
+        );
+    }
+}
+
+/// Where a pattern was found in the asserted value
+enum Occurrence<Pattern> {
+    Found {
+        pattern: Pattern,
+        offset: usize,
+    },
+    /// Only found before the occurrence of the previous pattern ended
+    Before {
+        pattern: Pattern,
+        offset: usize,
+        expected_after: usize,
+    },
+    Missing {
+        pattern: Pattern,
+    },
+}
+
+impl<Pattern: AsRef<str>> Debug for Occurrence<Pattern> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Found { pattern, offset } => {
+                write!(f, "{:?} at byte {offset}", Escaped(pattern.as_ref()))
+            }
+            Self::Before {
+                pattern,
+                offset,
+                expected_after,
+            } => {
+                write!(
+                    f,
+                    "{:?} at byte {offset} but expected at or after byte {expected_after}",
+                    Escaped(pattern.as_ref())
+                )
+            }
+            Self::Missing { pattern } => write!(f, "{:?} nowhere", Escaped(pattern.as_ref())),
+        }
+    }
+}
+
+/// The styles of an occurrence of a text in the rendered value
+struct StyledOccurrence {
+    /// Byte offset in the rendered text
+    offset: usize,
+    styles: Styles,
+}
+
+impl Debug for StyledOccurrence {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{:?} at byte {}", self.styles, self.offset)
+    }
+}
+
+/// Renders one occurrence per line in the failure output
+struct Occurrences<T>(Vec<T>);
+
+impl<T: Debug> Debug for Occurrences<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (index, occurrence) in self.0.iter().enumerate() {
+            if index > 0 {
+                write!(f, "\n  ")?;
+            }
+            write!(f, "{occurrence:?}")?;
+        }
+
+        Ok(())
+    }
+}
+
+/// Unicode case folding by mapping each character to its uppercase and then to its lowercase form
+///
+/// Going through uppercase first folds characters like `ß` (`SS`) or `ς` (`Σ`) to the same form as their counterparts.
+fn fold_case(string: &str) -> String {
+    string
+        .chars()
+        .flat_map(char::to_uppercase)
+        .flat_map(char::to_lowercase)
+        .collect()
+}
+
+fn collapse_whitespace(string: &str) -> String {
+    string.split_whitespace().collect::<Vec<&str>>().join(" ")
+}
+
+fn remove_whitespace(string: &str) -> String {
+    string
+        .chars()
+        .filter(|character| !character.is_whitespace())
+        .collect()
+}
+
+fn normalize_line_endings(string: &str) -> String {
+    string.replace("\r\n", "\n").replace('\r', "\n")
+}
+
+/// Removes the indentation all non-blank lines have in common and empties blank lines
+fn dedent(string: &str) -> String {
+    let is_blank = |line: &str| line.trim().is_empty();
+    let indentation = |line: &str| {
+        line.chars()
+            .take_while(|character| character.is_whitespace())
+            .count()
+    };
+
+    let common_indentation = string
+        .split('\n')
+        .filter(|line| !is_blank(line))
+        .map(indentation)
+        .min()
+        .unwrap_or_default();
+
+    string
+        .split('\n')
+        .map(|line| {
+            if is_blank(line) {
+                String::new()
+            } else {
+                line.chars().skip(common_indentation).collect()
+            }
+        })
+        .collect::<Vec<String>>()
+        .join("\n")
+}
+
+/// Compiles the pattern and fails the assertion if it is no valid regex
+#[cfg(feature = "regex")]
+#[track_caller]
+fn compile(pattern: &str) -> regex::Regex {
+    let regex = regex::Regex::new(pattern);
+
+    if let Err(error) = &regex {
+        implementation::assert_with_additional_info_no_expected(
+            false,
+            Escaped(pattern),
+            "to be a valid regex",
+            "but compiling failed with",
+            Displayed(error),
+        );
+    }
+
+    #[allow(clippy::unwrap_used)]
+    regex.unwrap()
 }
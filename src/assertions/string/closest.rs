@@ -0,0 +1,135 @@
+//! Search for the part of a string closest to an expected pattern by edit distance
+use crate::implementation::ClosestMatch;
+
+/// Upper bound for the size of the edit distance table to keep failures of huge values fast
+const MAX_CELLS: usize = 10_000_000;
+
+/// Matches with a lower similarity in percent are not worth a hint
+const MIN_SIMILARITY: usize = 50;
+
+/// Where the closest match has to be located in the value
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub(super) enum Anchor {
+    Anywhere,
+    Start,
+    End,
+    /// The match has to span the whole value
+    Whole,
+}
+
+/// Distance to the expected pattern and index of the first character of the match
+#[derive(Clone, Copy)]
+struct Cell {
+    distance: usize,
+    start: usize,
+}
+
+impl Cell {
+    const fn step(self, cost: usize) -> Self {
+        Self {
+            distance: self.distance + cost,
+            start: self.start,
+        }
+    }
+
+    /// The cell with the lower distance, preferring `self` on ties
+    const fn or_closer(self, other: Self) -> Self {
+        if other.distance < self.distance {
+            other
+        } else {
+            self
+        }
+    }
+}
+
+/// Finds the part of the value with the lowest Levenshtein distance to the expected pattern
+///
+/// Returns [`None`] if the value is too large to search or the closest match is not similar enough.
+pub(super) fn closest_match<'a>(
+    actual: &'a str,
+    expected: &'a str,
+    anchor: Anchor,
+) -> Option<ClosestMatch<'a>> {
+    let characters = actual.char_indices().collect::<Vec<(usize, char)>>();
+    let expected_length = expected.chars().count();
+
+    if expected_length == 0 || characters.len().saturating_mul(expected_length) > MAX_CELLS {
+        return None;
+    }
+
+    // when the match may start anywhere, skipping characters of the value is free
+    let free_start = matches!(anchor, Anchor::Anywhere | Anchor::End);
+    let mut previous = (0..=characters.len())
+        .map(|index| {
+            if free_start {
+                Cell {
+                    distance: 0,
+                    start: index,
+                }
+            } else {
+                Cell {
+                    distance: index,
+                    start: 0,
+                }
+            }
+        })
+        .collect::<Vec<Cell>>();
+
+    for (row, expected_character) in expected.chars().enumerate() {
+        let mut current = Vec::with_capacity(previous.len());
+        let mut left = Cell {
+            distance: row + 1,
+            start: 0,
+        };
+        current.push(left);
+
+        for ((diagonal, up), (_offset, character)) in previous
+            .iter()
+            .zip(previous.iter().skip(1))
+            .zip(&characters)
+        {
+            let cost = usize::from(*character != expected_character);
+            left = diagonal
+                .step(cost)
+                .or_closer(up.step(1))
+                .or_closer(left.step(1));
+            current.push(left);
+        }
+
+        previous = current;
+    }
+
+    let (end, closest) = if matches!(anchor, Anchor::End | Anchor::Whole) {
+        (characters.len(), *previous.last()?)
+    } else {
+        previous
+            .iter()
+            .copied()
+            .enumerate()
+            .reduce(|closest, candidate| {
+                if candidate.1.distance < closest.1.distance {
+                    candidate
+                } else {
+                    closest
+                }
+            })?
+    };
+
+    let byte_offset = |index: usize| {
+        characters
+            .get(index)
+            .map_or(actual.len(), |(offset, _character)| *offset)
+    };
+    let offset = byte_offset(closest.start);
+    let candidate = actual.get(offset..byte_offset(end))?;
+
+    let longest = expected_length.max(end - closest.start);
+    let similarity = (100 * (longest - closest.distance)).div_euclid(longest);
+
+    (closest.distance > 0 && similarity >= MIN_SIMILARITY).then_some(ClosestMatch {
+        candidate,
+        expected,
+        offset,
+        similarity,
+    })
+}
@@ -0,0 +1,222 @@
+//! Dependency-free matching of glob and wildcard patterns against whole strings
+use std::{
+    fmt::{self, Debug},
+    iter::Peekable,
+    str::CharIndices,
+};
+
+/// The features of the pattern syntax
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub(super) enum Syntax {
+    /// `*`, `?` and escaping with `\`
+    Wildcard,
+    /// Like [`Syntax::Wildcard`] plus character classes like `[a-z]` or `[!0-9]`
+    Glob,
+}
+
+/// A pattern compiled into its tokens
+pub(super) struct Pattern<'a> {
+    source: &'a str,
+    tokens: Vec<Token>,
+}
+
+struct Token {
+    kind: Kind,
+    /// Byte offset of the token in the source of the pattern
+    offset: usize,
+}
+
+enum Kind {
+    Literal(char),
+    AnyChar,
+    AnyRun,
+    Class {
+        negated: bool,
+        ranges: Vec<(char, char)>,
+    },
+}
+
+impl Kind {
+    fn matches(&self, character: char) -> bool {
+        match self {
+            Self::Literal(literal) => *literal == character,
+            Self::AnyChar | Self::AnyRun => true,
+            Self::Class { negated, ranges } => {
+                let contained = ranges
+                    .iter()
+                    .any(|(start, end)| (*start..=*end).contains(&character));
+                contained != *negated
+            }
+        }
+    }
+}
+
+/// Where matching the value failed after trying all possibilities
+pub(super) struct Divergence<'a> {
+    value: &'a str,
+    value_offset: usize,
+    pattern: &'a str,
+    pattern_offset: usize,
+}
+
+impl Debug for Divergence<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "byte {} of the value ({:?}) and byte {} of the pattern ({:?})",
+            self.value_offset,
+            super::Escaped(self.value.get(self.value_offset..).unwrap_or_default()),
+            self.pattern_offset,
+            super::Escaped(self.pattern.get(self.pattern_offset..).unwrap_or_default()),
+        )
+    }
+}
+
+impl<'a> Pattern<'a> {
+    /// Compiles the pattern or describes why it is invalid
+    pub(super) fn compile(source: &'a str, syntax: Syntax) -> Result<Self, String> {
+        let mut tokens = Vec::new();
+        let mut characters = source.char_indices().peekable();
+
+        while let Some((offset, character)) = characters.next() {
+            let kind = match character {
+                '*' => Kind::AnyRun,
+                '?' => Kind::AnyChar,
+                '\\' => {
+                    let (_escape_offset, escaped) = characters
+                        .next()
+                        .ok_or_else(|| format!("dangling escape at byte {offset}"))?;
+                    Kind::Literal(escaped)
+                }
+                '[' if syntax == Syntax::Glob => class(&mut characters, offset)?,
+                literal => Kind::Literal(literal),
+            };
+
+            tokens.push(Token { kind, offset });
+        }
+
+        Ok(Self { source, tokens })
+    }
+
+    /// Matches the whole value against the pattern
+    ///
+    /// Runs of `*` are matched by backtracking to the most recent one only, which keeps matching linear in most cases.
+    /// On failure the divergence is the point where the most of the pattern was matched.
+    pub(super) fn matches<'b>(&self, value: &'b str) -> Result<(), Divergence<'b>>
+    where
+        'a: 'b,
+    {
+        let characters = value.char_indices().collect::<Vec<(usize, char)>>();
+        let mut value_index = 0;
+        let mut token_index = 0;
+        let mut last_run: Option<(usize, usize)> = None;
+        let mut furthest = (0, 0);
+
+        loop {
+            match self.tokens.get(token_index) {
+                Some(Token {
+                    kind: Kind::AnyRun, ..
+                }) => {
+                    last_run = Some((token_index, value_index));
+                    token_index += 1;
+                    continue;
+                }
+                Some(token) => {
+                    if let Some((_offset, character)) = characters.get(value_index) {
+                        if token.kind.matches(*character) {
+                            value_index += 1;
+                            token_index += 1;
+                            continue;
+                        }
+                    }
+                }
+                None if value_index == characters.len() => return Ok(()),
+                None => {}
+            }
+
+            furthest = furthest.max((token_index, value_index));
+
+            match last_run {
+                Some((run_index, run_start)) if run_start < characters.len() => {
+                    last_run = Some((run_index, run_start + 1));
+                    token_index = run_index + 1;
+                    value_index = run_start + 1;
+                }
+                _ => break,
+            }
+        }
+
+        let (token_index, value_index) = furthest;
+
+        Err(Divergence {
+            value,
+            value_offset: characters
+                .get(value_index)
+                .map_or(value.len(), |(offset, _character)| *offset),
+            pattern: self.source,
+            pattern_offset: self
+                .tokens
+                .get(token_index)
+                .map_or(self.source.len(), |token| token.offset),
+        })
+    }
+}
+
+type Characters<'a> = Peekable<CharIndices<'a>>;
+
+/// Parses a character class after its opening `[` which is located at the offset
+fn class(characters: &mut Characters<'_>, offset: usize) -> Result<Kind, String> {
+    let negated = characters
+        .next_if(|(_next_offset, next)| matches!(next, '!' | '^'))
+        .is_some();
+    let mut ranges = Vec::new();
+
+    loop {
+        // a `]` directly after the opening bracket is part of the class
+        if !ranges.is_empty()
+            && characters
+                .next_if(|(_next_offset, next)| *next == ']')
+                .is_some()
+        {
+            return Ok(Kind::Class { negated, ranges });
+        }
+
+        let start = class_member(characters, offset)?;
+        let is_range = characters
+            .peek()
+            .is_some_and(|(_dash_offset, dash)| *dash == '-')
+            && characters
+                .clone()
+                .nth(1)
+                .is_some_and(|(_end_offset, end)| end != ']');
+
+        if is_range {
+            characters.next();
+            let end = class_member(characters, offset)?;
+            if end < start {
+                return Err(format!(
+                    "invalid range {start}-{end} in the character class at byte {offset}"
+                ));
+            }
+            ranges.push((start, end));
+        } else {
+            ranges.push((start, start));
+        }
+    }
+}
+
+/// Parses a single, possibly escaped, member of a character class
+fn class_member(characters: &mut Characters<'_>, offset: usize) -> Result<char, String> {
+    let unclosed = || format!("unclosed character class at byte {offset}");
+
+    match characters.next() {
+        Some((_escape_offset, '\\')) => {
+            characters
+                .next()
+                .map(|(_escaped_offset, escaped)| escaped)
+                .ok_or_else(unclosed)
+        }
+        Some((_member_offset, member)) => Ok(member),
+        None => Err(unclosed()),
+    }
+}
@@ -0,0 +1,163 @@
+//! Matching of templates with `{name}` and `{*}` placeholders against whole strings
+use std::fmt::{self, Debug};
+
+/// A template split into literal text and placeholders
+pub(super) struct Template {
+    parts: Vec<Part>,
+}
+
+enum Part {
+    Literal(String),
+    /// `None` for the ignored placeholder `{*}`
+    Placeholder(Option<String>),
+}
+
+/// Where the literal text of the template was not found in the value
+pub(super) struct Divergence<'a> {
+    value: &'a str,
+    value_offset: usize,
+    literal: String,
+}
+
+impl Debug for Divergence<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "byte {} of the value ({:?}) where the template expects {:?}",
+            self.value_offset,
+            super::Escaped(self.value.get(self.value_offset..).unwrap_or_default()),
+            super::Escaped(&self.literal),
+        )
+    }
+}
+
+impl Template {
+    /// Parses the template or describes why it is invalid
+    pub(super) fn parse(source: &str) -> Result<Self, String> {
+        let mut parts = Vec::new();
+        let mut literal = String::new();
+        let mut characters = source.char_indices().peekable();
+
+        while let Some((offset, character)) = characters.next() {
+            match character {
+                '{' if characters
+                    .next_if(|(_next_offset, next)| *next == '{')
+                    .is_some() =>
+                {
+                    literal.push('{');
+                }
+                '}' if characters
+                    .next_if(|(_next_offset, next)| *next == '}')
+                    .is_some() =>
+                {
+                    literal.push('}');
+                }
+                '}' => return Err(format!("unmatched '}}' at byte {offset}")),
+                '{' => {
+                    let mut name = String::new();
+                    loop {
+                        match characters.next() {
+                            Some((_closing_offset, '}')) => break,
+                            Some((_name_offset, name_character)) => name.push(name_character),
+                            None => return Err(format!("unclosed placeholder at byte {offset}")),
+                        }
+                    }
+
+                    let placeholder = match name.as_str() {
+                        "" => return Err(format!("unnamed placeholder at byte {offset}")),
+                        "*" => None,
+                        _ if parts.iter().any(
+                            |part| matches!(part, Part::Placeholder(Some(other)) if *other == name),
+                        ) =>
+                        {
+                            return Err(format!("duplicate placeholder {name:?} at byte {offset}"));
+                        }
+                        _ => Some(name),
+                    };
+
+                    parts.push(Part::Literal(std::mem::take(&mut literal)));
+                    parts.push(Part::Placeholder(placeholder));
+                }
+                literal_character => literal.push(literal_character),
+            }
+        }
+
+        parts.push(Part::Literal(literal));
+
+        Ok(Self { parts })
+    }
+
+    /// Matches the whole value against the template and returns the values of the named placeholders
+    ///
+    /// Placeholders match as little text as possible except for the last one which extends up to the final literal text.
+    pub(super) fn matches<'a>(
+        &self,
+        value: &'a str,
+    ) -> Result<Vec<(String, String)>, Divergence<'a>> {
+        let mut captured = Vec::new();
+        let mut cursor = 0;
+        let mut open_placeholder: Option<&Option<String>> = None;
+        let last_index = self.parts.len().saturating_sub(1);
+
+        for (index, part) in self.parts.iter().enumerate() {
+            let text = match part {
+                Part::Placeholder(name) => {
+                    open_placeholder = Some(name);
+                    continue;
+                }
+                Part::Literal(text) => text,
+            };
+            let remaining = value.get(cursor..).unwrap_or_default();
+
+            let maybe_found = match open_placeholder {
+                // the literal text has to follow directly
+                None if index == last_index => (remaining == text).then_some(0),
+                None => remaining.starts_with(text.as_str()).then_some(0),
+                Some(_placeholder) if index == last_index => {
+                    remaining
+                        .ends_with(text.as_str())
+                        .then(|| remaining.len() - text.len())
+                }
+                Some(_placeholder) => remaining.find(text.as_str()),
+            };
+
+            let Some(found) = maybe_found else {
+                let common = if open_placeholder.is_none() {
+                    common_prefix(remaining, text)
+                } else {
+                    0
+                };
+
+                return Err(Divergence {
+                    value,
+                    value_offset: cursor + common,
+                    literal: text.get(common..).unwrap_or_default().to_string(),
+                });
+            };
+
+            if let Some(Some(name)) = open_placeholder {
+                captured.push((
+                    name.clone(),
+                    remaining.get(..found).unwrap_or_default().to_string(),
+                ));
+            }
+
+            cursor += found + text.len();
+            open_placeholder = None;
+        }
+
+        Ok(captured)
+    }
+}
+
+/// The length in bytes of the longest common prefix
+fn common_prefix(first: &str, second: &str) -> usize {
+    first
+        .char_indices()
+        .zip(second.chars())
+        .find(|((_offset, left), right)| left != right)
+        .map_or_else(
+            || first.len().min(second.len()),
+            |((offset, _left), _right)| offset,
+        )
+}
@@ -0,0 +1,467 @@
+use crate::{implementation, private, Asserter};
+use std::{
+    fmt::{self, Debug},
+    time::{Duration, Instant, SystemTime, UNIX_EPOCH},
+};
+
+/// Specifies various assertions on points in time like [`SystemTime`] and [`Instant`]. Implemented on [`Asserter`]
+///
+/// [`SystemTime`]s are printed as RFC 3339 timestamps in the failure output.
+///
+/// With the `chrono` or `time` feature enabled the assertions are also available for the date and time types of these crates.
+/// Their naive representations without a timezone are interpreted as UTC.
+///
+/// This trait is sealed and cannot be implemented outside Smoothy.
+pub trait TimeAssertion<Time>: private::Sealed {
+    /// Asserts that the point in time lies before the expected one.
+    ///
+    /// # Examples
+    /// ```
+    /// # use smoothy::prelude::*;
+    /// # use std::time::{Duration, SystemTime};
+    /// #
+    /// let now = SystemTime::now();
+    /// assert_that(now).is_before(now + Duration::from_secs(1));
+    /// ```
+    ///
+    /// ```should_panic
+    /// # use smoothy::prelude::*;
+    /// # use std::time::{Duration, SystemTime};
+    /// #
+    /// let now = SystemTime::now();
+    /// assert_that(now).is_before(now - Duration::from_secs(1));
+    /// ```
+    ///
+    /// # Panics
+    /// When the point in time is equal to or after the expected one
+    #[track_caller]
+    #[allow(clippy::wrong_self_convention)]
+    fn is_before(self, expected: Time) -> Asserter<Time>;
+
+    /// Asserts that the point in time lies after the expected one.
+    ///
+    /// # Examples
+    /// ```
+    /// # use smoothy::prelude::*;
+    /// # use std::time::{Duration, Instant};
+    /// #
+    /// let now = Instant::now();
+    /// assert_that(now + Duration::from_secs(1)).is_after(now);
+    /// ```
+    ///
+    /// ```should_panic
+    /// # use smoothy::prelude::*;
+    /// # use std::time::Instant;
+    /// #
+    /// let now = Instant::now();
+    /// assert_that(now).is_after(now);
+    /// ```
+    ///
+    /// # Panics
+    /// When the point in time is equal to or before the expected one
+    #[track_caller]
+    #[allow(clippy::wrong_self_convention)]
+    fn is_after(self, expected: Time) -> Asserter<Time>;
+
+    /// Asserts that the point in time lies between the start and the end (both inclusive).
+    ///
+    /// # Examples
+    /// ```
+    /// # use smoothy::prelude::*;
+    /// # use std::time::{Duration, SystemTime};
+    /// #
+    /// let now = SystemTime::now();
+    /// assert_that(now).is_between(now - Duration::from_secs(1), now + Duration::from_secs(1));
+    /// ```
+    ///
+    /// ```should_panic
+    /// # use smoothy::prelude::*;
+    /// # use std::time::{Duration, SystemTime};
+    /// #
+    /// let now = SystemTime::now();
+    /// assert_that(now).is_between(now + Duration::from_secs(1), now + Duration::from_secs(2));
+    /// ```
+    ///
+    /// # Panics
+    /// When the point in time is before the start or after the end
+    #[track_caller]
+    #[allow(clippy::wrong_self_convention)]
+    fn is_between(self, start: Time, end: Time) -> Asserter<Time>;
+
+    /// Asserts that the point in time lies close to another one.
+    ///
+    /// The other point in time has to be specified with [`of`](WithinAsserter::of).
+    ///
+    /// # Examples
+    /// ```
+    /// # use smoothy::prelude::*;
+    /// # use std::time::{Duration, SystemTime};
+    /// #
+    /// let now = SystemTime::now();
+    /// assert_that(now + Duration::from_millis(10))
+    ///     .is_within(Duration::from_millis(50))
+    ///     .of(now);
+    /// ```
+    #[track_caller]
+    #[allow(clippy::wrong_self_convention)]
+    #[must_use = "Without specifying the other point in time with .of() this does not assert anything"]
+    fn is_within(self, tolerance: Duration) -> WithinAsserter<Time>;
+
+    /// Asserts that the point in time is not after the current time.
+    ///
+    /// # Examples
+    /// ```
+    /// # use smoothy::prelude::*;
+    /// # use std::time::{Duration, SystemTime};
+    /// #
+    /// assert_that(SystemTime::now() - Duration::from_secs(60)).is_in_the_past();
+    /// ```
+    ///
+    /// ```should_panic
+    /// # use smoothy::prelude::*;
+    /// # use std::time::{Duration, SystemTime};
+    /// #
+    /// assert_that(SystemTime::now() + Duration::from_secs(60)).is_in_the_past();
+    /// ```
+    ///
+    /// # Panics
+    /// When the point in time is after the current time
+    #[track_caller]
+    #[allow(clippy::wrong_self_convention)]
+    fn is_in_the_past(self) -> Asserter<Time>;
+
+    /// Asserts that the point in time is after the current time.
+    ///
+    /// # Examples
+    /// ```
+    /// # use smoothy::prelude::*;
+    /// # use std::time::{Duration, Instant};
+    /// #
+    /// assert_that(Instant::now() + Duration::from_secs(60)).is_in_the_future();
+    /// ```
+    ///
+    /// ```should_panic
+    /// # use smoothy::prelude::*;
+    /// # use std::time::{Duration, SystemTime};
+    /// #
+    /// assert_that(SystemTime::now() - Duration::from_secs(60)).is_in_the_future();
+    /// ```
+    ///
+    /// # Panics
+    /// When the point in time is not after the current time
+    #[track_caller]
+    #[allow(clippy::wrong_self_convention)]
+    fn is_in_the_future(self) -> Asserter<Time>;
+
+    /// Convenience function for getting the time elapsed since the point in time.
+    ///
+    /// # Examples
+    /// ```
+    /// # use smoothy::prelude::*;
+    /// # use std::time::{Duration, SystemTime};
+    /// #
+    /// assert_that(SystemTime::now() - Duration::from_secs(60))
+    ///     .elapsed()
+    ///     .is_longer_than(Duration::from_secs(59));
+    /// ```
+    ///
+    /// # Panics
+    /// When the point in time is after the current time
+    #[track_caller]
+    #[must_use = "Accessing the elapsed time only asserts that the point in time is in the past"]
+    fn elapsed(self) -> Asserter<Duration>;
+}
+
+/// Abstraction over the different representations of a point in time accepted by [`TimeAssertion`]
+///
+/// Implemented for [`SystemTime`] and [`Instant`] and, with the `chrono` or `time` feature enabled,
+/// for the date and time types of these crates.
+///
+/// This trait is sealed and cannot be implemented outside Smoothy.
+pub trait Timestamp: PartialOrd + private::Sealed {
+    /// Time passed since the point in time
+    ///
+    /// # Errors
+    /// The time until the point in time is reached if it lies in the future
+    fn elapsed(&self) -> Result<Duration, Duration>;
+
+    /// The absolute time between two points in time
+    fn distance(&self, other: &Self) -> Duration;
+
+    /// Human-readable representation used in the failure output
+    ///
+    /// # Errors
+    /// When writing to the formatter fails
+    fn fmt_timestamp(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result;
+}
+
+impl private::Sealed for SystemTime {}
+
+impl Timestamp for SystemTime {
+    fn elapsed(&self) -> Result<Duration, Duration> {
+        Self::now()
+            .duration_since(*self)
+            .map_err(|error| error.duration())
+    }
+
+    fn distance(&self, other: &Self) -> Duration {
+        self.duration_since(*other)
+            .unwrap_or_else(|error| error.duration())
+    }
+
+    fn fmt_timestamp(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let (seconds, nanos) = match self.duration_since(UNIX_EPOCH) {
+            Ok(since_epoch) => {
+                (
+                    i64::try_from(since_epoch.as_secs()).unwrap_or(i64::MAX),
+                    since_epoch.subsec_nanos(),
+                )
+            }
+            Err(error) => {
+                let before_epoch = error.duration();
+                let seconds = i64::try_from(before_epoch.as_secs()).unwrap_or(i64::MAX);
+                match before_epoch.subsec_nanos() {
+                    0 => (-seconds, 0),
+                    nanos => (-seconds - 1, 1_000_000_000 - nanos),
+                }
+            }
+        };
+
+        fmt_rfc3339(f, seconds, nanos)
+    }
+}
+
+impl private::Sealed for Instant {}
+
+impl Timestamp for Instant {
+    fn elapsed(&self) -> Result<Duration, Duration> {
+        let now = Self::now();
+        now.checked_duration_since(*self)
+            .ok_or_else(|| self.duration_since(now))
+    }
+
+    fn distance(&self, other: &Self) -> Duration {
+        if self > other {
+            self.duration_since(*other)
+        } else {
+            other.duration_since(*self)
+        }
+    }
+
+    fn fmt_timestamp(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{self:?}")
+    }
+}
+
+/// Writes the seconds and nanoseconds since the unix epoch as RFC 3339 timestamp in UTC
+fn fmt_rfc3339(f: &mut fmt::Formatter<'_>, seconds: i64, nanos: u32) -> fmt::Result {
+    let days = seconds.div_euclid(86_400);
+    let seconds_of_day = seconds.rem_euclid(86_400);
+
+    // see https://howardhinnant.github.io/date_algorithms.html#civil_from_days
+    let shifted_days = days + 719_468;
+    let era = shifted_days.div_euclid(146_097);
+    let day_of_era = shifted_days.rem_euclid(146_097);
+    let year_of_era = (day_of_era - day_of_era.div_euclid(1_460) + day_of_era.div_euclid(36_524)
+        - day_of_era.div_euclid(146_096))
+    .div_euclid(365);
+    let day_of_year =
+        day_of_era - (365 * year_of_era + year_of_era.div_euclid(4) - year_of_era.div_euclid(100));
+    let shifted_month = (5 * day_of_year + 2).div_euclid(153);
+    let day = day_of_year - (153 * shifted_month + 2).div_euclid(5) + 1;
+    let month = if shifted_month < 10 {
+        shifted_month + 3
+    } else {
+        shifted_month - 9
+    };
+    let year = year_of_era + era * 400 + i64::from(month <= 2);
+
+    write!(
+        f,
+        "{year:04}-{month:02}-{day:02}T{:02}:{:02}:{:02}",
+        seconds_of_day.div_euclid(3_600),
+        seconds_of_day.rem_euclid(3_600).div_euclid(60),
+        seconds_of_day.rem_euclid(60),
+    )?;
+
+    if nanos > 0 {
+        let fraction = format!("{nanos:09}");
+        write!(f, ".{}", fraction.trim_end_matches('0'))?;
+    }
+
+    write!(f, "Z")
+}
+
+/// Renders a point in time in the failure output
+pub struct Formatted<'a, Time>(pub &'a Time);
+
+impl<Time: Timestamp> Debug for Formatted<'_, Time> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.0.fmt_timestamp(f)
+    }
+}
+
+impl<Time> TimeAssertion<Time> for Asserter<Time>
+where
+    Time: Timestamp,
+{
+    fn is_before(self, expected: Time) -> Self {
+        implementation::assert_with_reason(
+            self.value != expected,
+            Formatted(&self.value),
+            "to be before",
+            Formatted(&expected),
+            "but was equal",
+        );
+        implementation::assert_with_additional_info(
+            self.value < expected,
+            Formatted(&self.value),
+            "to be before",
+            Formatted(&expected),
+            "but was later by",
+            self.value.distance(&expected),
+        );
+
+        self
+    }
+
+    fn is_after(self, expected: Time) -> Self {
+        implementation::assert_with_reason(
+            self.value != expected,
+            Formatted(&self.value),
+            "to be after",
+            Formatted(&expected),
+            "but was equal",
+        );
+        implementation::assert_with_additional_info(
+            self.value > expected,
+            Formatted(&self.value),
+            "to be after",
+            Formatted(&expected),
+            "but was earlier by",
+            self.value.distance(&expected),
+        );
+
+        self
+    }
+
+    fn is_between(self, start: Time, end: Time) -> Self {
+        implementation::assert(
+            start <= self.value && self.value <= end,
+            Formatted(&self.value),
+            "to be between",
+            [Formatted(&start), Formatted(&end)],
+        );
+
+        self
+    }
+
+    fn is_within(self, tolerance: Duration) -> WithinAsserter<Time> {
+        WithinAsserter {
+            value: self.value,
+            tolerance,
+        }
+    }
+
+    fn is_in_the_past(self) -> Self {
+        if let Err(ahead) = self.value.elapsed() {
+            implementation::assert_with_additional_info_no_expected(
+                false,
+                Formatted(&self.value),
+                "to be in the past",
+                "but is ahead of now by",
+                ahead,
+            );
+        }
+
+        self
+    }
+
+    fn is_in_the_future(self) -> Self {
+        if let Ok(elapsed) = self.value.elapsed() {
+            implementation::assert_with_additional_info_no_expected(
+                false,
+                Formatted(&self.value),
+                "to be in the future",
+                "but is behind now by",
+                elapsed,
+            );
+        }
+
+        self
+    }
+
+    fn elapsed(self) -> Asserter<Duration> {
+        let elapsed = self.value.elapsed();
+
+        if let Err(ahead) = elapsed {
+            implementation::assert_with_additional_info_no_expected(
+                false,
+                Formatted(&self.value),
+                "to be in the past",
+                "but is ahead of now by",
+                ahead,
+            );
+        }
+
+        #[allow(clippy::unwrap_used)]
+        let value = elapsed.unwrap();
+
+        Asserter { value }
+    }
+}
+
+/// Enables asserting that a point in time is close to another one
+pub struct WithinAsserter<Time> {
+    value: Time,
+    tolerance: Duration,
+}
+
+impl<Time> WithinAsserter<Time>
+where
+    Time: Timestamp,
+{
+    /// Asserts that the point in time deviates at most by the tolerance from the expected one.
+    ///
+    /// # Examples
+    /// ```
+    /// # use smoothy::prelude::*;
+    /// # use std::time::{Duration, Instant};
+    /// #
+    /// let start = Instant::now();
+    /// let asserter: Asserter<Instant> = assert_that(Instant::now())
+    ///     .is_within(Duration::from_secs(1))
+    ///     .of(start);
+    /// // further assertions
+    /// asserter.is_in_the_past();
+    /// ```
+    ///
+    /// ```should_panic
+    /// # use smoothy::prelude::*;
+    /// # use std::time::{Duration, SystemTime};
+    /// #
+    /// let now = SystemTime::now();
+    /// assert_that(now + Duration::from_secs(2))
+    ///     .is_within(Duration::from_secs(1))
+    ///     .of(now);
+    /// ```
+    ///
+    /// # Panics
+    /// When the point in time deviates by more than the tolerance
+    #[track_caller]
+    #[allow(clippy::must_use_candidate, clippy::needless_pass_by_value)]
+    pub fn of(self, expected: Time) -> Asserter<Time> {
+        let deviation = self.value.distance(&expected);
+
+        implementation::assert_with_additional_info(
+            deviation <= self.tolerance,
+            Formatted(&self.value),
+            &format!("to be within {:?} of", self.tolerance),
+            Formatted(&expected),
+            "but differed by",
+            deviation,
+        );
+
+        Asserter { value: self.value }
+    }
+}
@@ -0,0 +1,436 @@
+use crate::{
+    assertions::time::{Formatted, Timestamp},
+    implementation::{self, Displayed},
+    private, Asserter,
+};
+use std::{fmt, time::Duration};
+use time::{
+    format_description::{self, well_known::Rfc3339},
+    Date, Month, OffsetDateTime, PrimitiveDateTime, Weekday,
+};
+
+/// Specifies calendar assertions on dates of the [`time`] crate. Implemented on [`Asserter`]
+///
+/// Available for [`Date`], [`PrimitiveDateTime`] and [`OffsetDateTime`].
+/// The calendar values of an [`OffsetDateTime`] are the ones in its offset.
+///
+/// This trait is sealed and cannot be implemented outside Smoothy.
+pub trait TimeCalendarAssertion<Calendar>: private::Sealed
+where
+    Calendar: CalendarDate,
+{
+    /// Convenience function for getting the year of the date.
+    ///
+    /// # Examples
+    /// ```
+    /// # use smoothy::prelude::*;
+    /// # use time::{Date, Month};
+    /// #
+    /// let date = Date::from_calendar_date(2026, Month::October, 19).unwrap();
+    /// assert_that(date).year().is(2026);
+    /// ```
+    #[track_caller]
+    #[must_use = "Accessing the year does not assert anything"]
+    fn year(self) -> Asserter<i32>;
+
+    /// Convenience function for getting the month of the date.
+    ///
+    /// # Examples
+    /// ```
+    /// # use smoothy::prelude::*;
+    /// # use time::{Date, Month};
+    /// #
+    /// let date = Date::from_calendar_date(2026, Month::October, 19).unwrap();
+    /// assert_that(date).month().is(Month::October);
+    /// ```
+    #[track_caller]
+    #[must_use = "Accessing the month does not assert anything"]
+    fn month(self) -> Asserter<Month>;
+
+    /// Convenience function for getting the day of the month of the date starting at 1.
+    ///
+    /// # Examples
+    /// ```
+    /// # use smoothy::prelude::*;
+    /// # use time::{Date, Month};
+    /// #
+    /// let date = Date::from_calendar_date(2026, Month::October, 19).unwrap();
+    /// assert_that(date).day().is(19);
+    /// ```
+    #[track_caller]
+    #[must_use = "Accessing the day does not assert anything"]
+    fn day(self) -> Asserter<u8>;
+
+    /// Convenience function for getting the day of the week of the date.
+    ///
+    /// # Examples
+    /// ```
+    /// # use smoothy::prelude::*;
+    /// # use time::{Date, Month, Weekday};
+    /// #
+    /// let date = Date::from_calendar_date(2026, Month::October, 19).unwrap();
+    /// assert_that(date).weekday().is(Weekday::Monday);
+    /// ```
+    #[track_caller]
+    #[must_use = "Accessing the weekday does not assert anything"]
+    fn weekday(self) -> Asserter<Weekday>;
+
+    /// Asserts that the date falls on the expected calendar day.
+    ///
+    /// # Examples
+    /// ```
+    /// # use smoothy::prelude::*;
+    /// # use time::{Date, Month, OffsetDateTime};
+    /// #
+    /// let date_time = OffsetDateTime::from_unix_timestamp(1_792_000_000).unwrap();
+    /// assert_that(date_time)
+    ///     .is_same_day_as(Date::from_calendar_date(2026, Month::October, 14).unwrap());
+    /// ```
+    ///
+    /// ```should_panic
+    /// # use smoothy::prelude::*;
+    /// # use time::{Date, Month};
+    /// #
+    /// let date = Date::from_calendar_date(2026, Month::October, 19).unwrap();
+    /// assert_that(date).is_same_day_as(Date::from_calendar_date(2026, Month::October, 20).unwrap());
+    /// ```
+    ///
+    /// # Panics
+    /// When the date is on another day
+    #[track_caller]
+    #[allow(clippy::wrong_self_convention)]
+    fn is_same_day_as(self, expected: impl CalendarDate) -> Asserter<Calendar>;
+}
+
+impl<Calendar> TimeCalendarAssertion<Calendar> for Asserter<Calendar>
+where
+    Calendar: CalendarDate,
+{
+    fn year(self) -> Asserter<i32> {
+        Asserter {
+            value: self.value.calendar_date().year(),
+        }
+    }
+
+    fn month(self) -> Asserter<Month> {
+        Asserter {
+            value: self.value.calendar_date().month(),
+        }
+    }
+
+    fn day(self) -> Asserter<u8> {
+        Asserter {
+            value: self.value.calendar_date().day(),
+        }
+    }
+
+    fn weekday(self) -> Asserter<Weekday> {
+        Asserter {
+            value: self.value.calendar_date().weekday(),
+        }
+    }
+
+    fn is_same_day_as(self, expected: impl CalendarDate) -> Self {
+        implementation::assert(
+            self.value.calendar_date() == expected.calendar_date(),
+            Formatted(&self.value),
+            "to be on the same day as",
+            Formatted(&expected),
+        );
+
+        self
+    }
+}
+
+/// Specifies assertions on [`OffsetDateTime`]. Implemented on [`Asserter`]
+///
+/// This trait is sealed and cannot be implemented outside Smoothy.
+pub trait OffsetDateTimeAssertion: private::Sealed {
+    /// Asserts that the date time describes the same instant as the expected one regardless of their offsets.
+    ///
+    /// # Examples
+    /// ```
+    /// # use smoothy::prelude::*;
+    /// # use time::{OffsetDateTime, UtcOffset};
+    /// #
+    /// let utc = OffsetDateTime::from_unix_timestamp(1_792_000_000).unwrap();
+    /// let cest = UtcOffset::from_hms(2, 0, 0).unwrap();
+    ///
+    /// assert_that(utc).is_same_instant_as(utc.to_offset(cest));
+    /// ```
+    ///
+    /// ```should_panic
+    /// # use smoothy::prelude::*;
+    /// # use time::{OffsetDateTime, UtcOffset};
+    /// #
+    /// let utc = OffsetDateTime::from_unix_timestamp(1_792_000_000).unwrap();
+    /// let cest = UtcOffset::from_hms(2, 0, 0).unwrap();
+    ///
+    /// assert_that(utc).is_same_instant_as(utc.replace_offset(cest));
+    /// ```
+    ///
+    /// # Panics
+    /// When the date times describe different instants
+    #[track_caller]
+    #[allow(clippy::wrong_self_convention)]
+    fn is_same_instant_as(self, expected: OffsetDateTime) -> Asserter<OffsetDateTime>;
+}
+
+impl OffsetDateTimeAssertion for Asserter<OffsetDateTime> {
+    fn is_same_instant_as(self, expected: OffsetDateTime) -> Self {
+        implementation::assert_with_additional_info(
+            self.value == expected,
+            Formatted(&self.value),
+            "to be the same instant as",
+            Formatted(&expected),
+            "but differed by",
+            self.value.distance(&expected),
+        );
+
+        self
+    }
+}
+
+/// Specifies assertions parsing string-likes into types of the [`time`] crate. Implemented on [`Asserter`]
+///
+/// Custom formats use the version 2 [format description](https://time-rs.github.io/book/api/format-description.html) syntax.
+///
+/// This trait is sealed and cannot be implemented outside Smoothy.
+pub trait TimeParseAssertion<StringLike>: private::Sealed
+where
+    StringLike: AsRef<str>,
+{
+    /// Asserts that the value is a valid RFC 3339 timestamp and converts it for further assertions.
+    ///
+    /// # Examples
+    /// ```
+    /// # use smoothy::prelude::*;
+    /// # use time::OffsetDateTime;
+    /// #
+    /// assert_that("2026-10-19T14:00:00+02:00")
+    ///     .to_time_offset_date_time()
+    ///     .is_same_instant_as(OffsetDateTime::from_unix_timestamp(1_792_411_200).unwrap());
+    /// ```
+    ///
+    /// ```should_panic
+    /// # use smoothy::prelude::*;
+    /// #
+    /// let _ = assert_that("2026-10-19 14:00").to_time_offset_date_time();
+    /// ```
+    ///
+    /// # Panics
+    /// When the value is no valid RFC 3339 timestamp
+    #[track_caller]
+    #[allow(clippy::wrong_self_convention)]
+    #[must_use = "Transforming the asserted value only asserts that it can be parsed"]
+    fn to_time_offset_date_time(self) -> Asserter<OffsetDateTime>;
+
+    /// Asserts that the value is a date in the given format and converts it for further assertions.
+    ///
+    /// # Examples
+    /// ```
+    /// # use smoothy::prelude::*;
+    /// #
+    /// assert_that("19.10.2026")
+    ///     .to_time_date("[day].[month].[year]")
+    ///     .year()
+    ///     .is(2026);
+    /// ```
+    ///
+    /// # Panics
+    /// When the format is invalid or the value cannot be parsed with it
+    #[track_caller]
+    #[allow(clippy::wrong_self_convention)]
+    #[must_use = "Transforming the asserted value only asserts that it can be parsed"]
+    fn to_time_date(self, format: &str) -> Asserter<Date>;
+
+    /// Asserts that the value is a date time in the given format and converts it for further assertions.
+    ///
+    /// # Examples
+    /// ```
+    /// # use smoothy::prelude::*;
+    /// # use time::{Date, Month};
+    /// #
+    /// assert_that("2026-10-19 13:37")
+    ///     .to_time_primitive_date_time("[year]-[month]-[day] [hour]:[minute]")
+    ///     .is_same_day_as(Date::from_calendar_date(2026, Month::October, 19).unwrap());
+    /// ```
+    ///
+    /// # Panics
+    /// When the format is invalid or the value cannot be parsed with it
+    #[track_caller]
+    #[allow(clippy::wrong_self_convention)]
+    #[must_use = "Transforming the asserted value only asserts that it can be parsed"]
+    fn to_time_primitive_date_time(self, format: &str) -> Asserter<PrimitiveDateTime>;
+}
+
+impl<StringLike> TimeParseAssertion<StringLike> for Asserter<StringLike>
+where
+    StringLike: AsRef<str>,
+{
+    fn to_time_offset_date_time(self) -> Asserter<OffsetDateTime> {
+        let actual = self.value.as_ref();
+
+        parsed(
+            actual,
+            OffsetDateTime::parse(actual, &Rfc3339),
+            "to be a RFC 3339 timestamp",
+        )
+    }
+
+    fn to_time_date(self, format: &str) -> Asserter<Date> {
+        let actual = self.value.as_ref();
+        let description = format_description_of(format);
+
+        parsed(
+            actual,
+            Date::parse(actual, &description),
+            &format!("to be a date in the format {format:?}"),
+        )
+    }
+
+    fn to_time_primitive_date_time(self, format: &str) -> Asserter<PrimitiveDateTime> {
+        let actual = self.value.as_ref();
+        let description = format_description_of(format);
+
+        parsed(
+            actual,
+            PrimitiveDateTime::parse(actual, &description),
+            &format!("to be a date time in the format {format:?}"),
+        )
+    }
+}
+
+#[track_caller]
+fn format_description_of(format: &str) -> Vec<format_description::BorrowedFormatItem<'_>> {
+    let description = format_description::parse_borrowed::<2>(format);
+
+    if let Err(error) = &description {
+        implementation::assert_with_additional_info_no_expected(
+            false,
+            format,
+            "to be a valid format description",
+            "but parsing failed with",
+            Displayed(error),
+        );
+    }
+
+    #[allow(clippy::unwrap_used)]
+    description.unwrap()
+}
+
+#[track_caller]
+fn parsed<Parsed>(
+    actual: &str,
+    result: Result<Parsed, time::error::Parse>,
+    assertion_desc: &str,
+) -> Asserter<Parsed> {
+    if let Err(error) = &result {
+        implementation::assert_with_additional_info_no_expected(
+            false,
+            actual,
+            assertion_desc,
+            "but parsing failed with",
+            Displayed(error),
+        );
+    }
+
+    #[allow(clippy::unwrap_used)]
+    let value = result.unwrap();
+
+    Asserter { value }
+}
+
+/// Types of the [`time`] crate that have a calendar date accepted by [`TimeCalendarAssertion`]
+///
+/// Implemented for [`Date`], [`PrimitiveDateTime`] and [`OffsetDateTime`].
+///
+/// This trait is sealed and cannot be implemented outside Smoothy.
+pub trait CalendarDate: Timestamp {
+    /// The calendar date
+    fn calendar_date(&self) -> Date;
+}
+
+impl CalendarDate for Date {
+    fn calendar_date(&self) -> Date {
+        *self
+    }
+}
+
+impl CalendarDate for PrimitiveDateTime {
+    fn calendar_date(&self) -> Date {
+        self.date()
+    }
+}
+
+impl CalendarDate for OffsetDateTime {
+    fn calendar_date(&self) -> Date {
+        self.date()
+    }
+}
+
+/// Time since the point in time or, if it lies in the future, the time until it is reached as error
+const fn elapsed_since(since: time::Duration) -> Result<Duration, Duration> {
+    if since.is_negative() {
+        Err(since.unsigned_abs())
+    } else {
+        Ok(since.unsigned_abs())
+    }
+}
+
+/// The current time in UTC without an offset
+fn now_utc() -> PrimitiveDateTime {
+    let now = OffsetDateTime::now_utc();
+    PrimitiveDateTime::new(now.date(), now.time())
+}
+
+impl private::Sealed for OffsetDateTime {}
+
+impl Timestamp for OffsetDateTime {
+    fn elapsed(&self) -> Result<Duration, Duration> {
+        elapsed_since(Self::now_utc() - *self)
+    }
+
+    fn distance(&self, other: &Self) -> Duration {
+        (*self - *other).unsigned_abs()
+    }
+
+    fn fmt_timestamp(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.format(&Rfc3339) {
+            Ok(formatted) => write!(f, "{formatted}"),
+            Err(_) => write!(f, "{self}"),
+        }
+    }
+}
+
+impl private::Sealed for PrimitiveDateTime {}
+
+impl Timestamp for PrimitiveDateTime {
+    fn elapsed(&self) -> Result<Duration, Duration> {
+        elapsed_since(now_utc() - *self)
+    }
+
+    fn distance(&self, other: &Self) -> Duration {
+        (*self - *other).unsigned_abs()
+    }
+
+    fn fmt_timestamp(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{self}")
+    }
+}
+
+impl private::Sealed for Date {}
+
+impl Timestamp for Date {
+    fn elapsed(&self) -> Result<Duration, Duration> {
+        elapsed_since(now_utc().date() - *self)
+    }
+
+    fn distance(&self, other: &Self) -> Duration {
+        (*self - *other).unsigned_abs()
+    }
+
+    fn fmt_timestamp(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{self}")
+    }
+}
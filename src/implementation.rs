@@ -8,6 +8,141 @@
 use colored::Colorize;
 use std::fmt::Debug;
 
+/// Renders a value with its [`Display`](std::fmt::Display) implementation in the failure output
+pub struct Displayed<T>(pub T);
+
+impl<T: std::fmt::Display> Debug for Displayed<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+/// Renders a string in quotes with invisible characters escaped in the failure output
+///
+/// In contrast to the [`Debug`] implementation of [`str`] this also escapes whitespace other than spaces
+/// (e.g. `\u{a0}`) and zero-width characters.
+pub struct Escaped<'a>(pub &'a str);
+
+impl Debug for Escaped<'_> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "\"")?;
+
+        for character in self.0.chars() {
+            write!(f, "{}", escape(character))?;
+        }
+
+        write!(f, "\"")
+    }
+}
+
+fn escape(character: char) -> String {
+    match character {
+        '\n' | '\r' | '\t' => character.escape_default().to_string(),
+        '\'' | ' ' => character.to_string(),
+        invisible if invisible.is_whitespace() || is_zero_width(invisible) => {
+            invisible.escape_unicode().to_string()
+        }
+        visible => visible.escape_debug().to_string(),
+    }
+}
+
+const fn is_zero_width(character: char) -> bool {
+    matches!(
+        character,
+        '\u{200b}' | '\u{200c}' | '\u{200d}' | '\u{2060}' | '\u{feff}'
+    )
+}
+
+/// Renders bytes as space-separated hex pairs in the failure output
+pub struct Hex<'a>(pub &'a [u8]);
+
+impl Debug for Hex<'_> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "[")?;
+
+        for (index, byte) in self.0.iter().enumerate() {
+            if index > 0 {
+                write!(f, " ")?;
+            }
+            write!(f, "{byte:02x}")?;
+        }
+
+        write!(f, "]")
+    }
+}
+
+/// Renders the normalized forms of the actual and expected values below each other in the failure output
+pub struct Normalized<Actual, Expected> {
+    pub actual: Actual,
+    pub expected: Expected,
+}
+
+impl<Actual: Debug, Expected: Debug> Debug for Normalized<Actual, Expected> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{:?}\n  {:?}", self.actual, self.expected)
+    }
+}
+
+/// The part of the actual value closest to the expected one which is shown as a hint in the failure output
+pub struct ClosestMatch<'a> {
+    pub candidate: &'a str,
+    pub expected: &'a str,
+    /// Byte offset of the candidate in the actual value
+    pub offset: usize,
+    /// Similarity to the expected value in percent
+    pub similarity: usize,
+}
+
+impl ClosestMatch<'_> {
+    /// Renders the escaped candidate with the characters differing from the expected value highlighted
+    /// and marked by carets in the line below
+    fn render(&self) -> String {
+        let length = self.candidate.chars().count();
+        // characters missing at the end are marked below the closing quote at index `length`
+        let mut is_differing = vec![false; length + 1];
+        let mut index = 0;
+
+        for change in
+            similar::TextDiff::from_chars(self.expected, self.candidate).iter_all_changes()
+        {
+            let differs = match change.tag() {
+                similar::ChangeTag::Equal => false,
+                similar::ChangeTag::Insert | similar::ChangeTag::Delete => true,
+            };
+            if differs {
+                if let Some(flag) = is_differing.get_mut(index) {
+                    *flag = true;
+                }
+            }
+            if change.tag() != similar::ChangeTag::Delete {
+                index += 1;
+            }
+        }
+
+        let mut highlighted = String::from("\"");
+        let mut carets = String::from(" ");
+
+        for (character, differs) in self.candidate.chars().zip(&is_differing) {
+            let escaped = escape(character);
+            let width = escaped.chars().count();
+
+            if *differs {
+                highlighted.push_str(&escaped.yellow().bold().to_string());
+                carets.push_str(&"^".repeat(width));
+            } else {
+                highlighted.push_str(&escaped);
+                carets.push_str(&" ".repeat(width));
+            }
+        }
+
+        if is_differing.last().copied().unwrap_or(false) {
+            carets.push('^');
+        }
+
+        format!("{highlighted}\"\n  {}", carets.trim_end().yellow().bold())
+    }
+}
+
 #[track_caller]
 pub(crate) fn assert_equals<T>(actual: &T, expected: T)
 where
@@ -16,6 +151,196 @@ where
     similar_asserts::assert_eq!(actual: actual, expected: &expected);
 }
 
+#[track_caller]
+pub(crate) fn assert_equals_normalized(actual: &str, expected: &str, normalization: &str) {
+    similar_asserts::assert_eq!(actual: actual, expected: expected, "{normalization}");
+}
+
+/// Compares the strings line by line and fails with a unified diff of the lines including line numbers
+#[track_caller]
+pub(crate) fn assert_equals_lines(actual: &str, expected: &str) {
+    let actual_lines = actual.lines().collect::<Vec<&str>>();
+    let expected_lines = expected.lines().collect::<Vec<&str>>();
+
+    if actual_lines == expected_lines {
+        return;
+    }
+
+    let ops =
+        similar::capture_diff_slices(similar::Algorithm::Myers, &actual_lines, &expected_lines);
+
+    let mut message = format!(
+        "{}\n\nExpected the lines to be equal but found differences ({}|{}):",
+        "Assertion failed!".red(),
+        "-actual".red(),
+        "+expected".green()
+    );
+
+    for group in similar::group_diff_ops(ops, 3) {
+        let (Some(first), Some(last)) = (group.first(), group.last()) else {
+            continue;
+        };
+        let old_start = first.old_range().start;
+        let new_start = first.new_range().start;
+
+        message = format!(
+            "{message}\n@@ -{},{} +{},{} @@",
+            old_start + 1,
+            last.old_range().end - old_start,
+            new_start + 1,
+            last.new_range().end - new_start
+        );
+
+        for change in group
+            .iter()
+            .flat_map(|op| op.iter_changes(&actual_lines, &expected_lines))
+        {
+            let line_number = |index: Option<usize>| {
+                index.map_or_else(String::new, |zero_based| (zero_based + 1).to_string())
+            };
+            let numbers = format!(
+                "{:>4} {:>4} |",
+                line_number(change.old_index()),
+                line_number(change.new_index())
+            );
+
+            let line = match change.tag() {
+                similar::ChangeTag::Equal => format!(" {}", change.value()),
+                similar::ChangeTag::Delete => format!("-{}", change.value()).red().to_string(),
+                similar::ChangeTag::Insert => format!("+{}", change.value()).green().to_string(),
+            };
+
+            message = format!("{message}\n{numbers}{line}");
+        }
+    }
+
+    panic!("{message}")
+}
+
+/// Number of bytes per row of the hexdump
+const HEXDUMP_WIDTH: usize = 8;
+
+/// Compares the bytes position by position and fails with a side-by-side hexdump of the differing rows
+#[track_caller]
+pub(crate) fn assert_equals_bytes(actual: &[u8], expected: &[u8]) {
+    if actual == expected {
+        return;
+    }
+
+    let length = actual.len().max(expected.len());
+    let differing = (0..length)
+        .filter(|offset| actual.get(*offset) != expected.get(*offset))
+        .count();
+    let rows = length.div_ceil(HEXDUMP_WIDTH);
+    let is_differing_row = (0..rows)
+        .map(|row| {
+            (row * HEXDUMP_WIDTH..(row + 1) * HEXDUMP_WIDTH)
+                .any(|offset| actual.get(offset) != expected.get(offset))
+        })
+        .collect::<Vec<bool>>();
+    let is_differing = |row: usize| is_differing_row.get(row).copied().unwrap_or(false);
+
+    let header = format!(
+        "{}\n\nExpected the bytes to be equal but they differ at {differing} {} ({} actual vs. {} expected bytes):\n{:>12}{}{}",
+        "Assertion failed!".red(),
+        if differing == 1 { "offset" } else { "offsets" },
+        actual.len(),
+        expected.len(),
+        "",
+        format!("{:<36}", "actual").red(),
+        "expected".green()
+    );
+    let mut lines = Vec::new();
+    let mut skipped = false;
+
+    for row in 0..rows {
+        // differing rows are shown with one row of context around them
+        let shown = is_differing(row)
+            || row.checked_sub(1).is_some_and(is_differing)
+            || is_differing(row + 1);
+
+        if !shown {
+            if !skipped {
+                lines.push(String::from("\n  ..."));
+            }
+            skipped = true;
+            continue;
+        }
+        skipped = false;
+
+        let offsets = row * HEXDUMP_WIDTH..(row + 1) * HEXDUMP_WIDTH;
+        let marker = if is_differing(row) { "!" } else { " " };
+
+        lines.push(format!(
+            "\n{marker} {:08x}  {}  {}",
+            row * HEXDUMP_WIDTH,
+            HexdumpRow {
+                bytes: actual,
+                other: expected,
+                offsets: offsets.clone(),
+                highlight: |cell| cell.red(),
+            },
+            HexdumpRow {
+                bytes: expected,
+                other: actual,
+                offsets,
+                highlight: |cell| cell.green(),
+            },
+        ));
+    }
+
+    panic!("{header}{}", lines.concat())
+}
+
+/// Renders the bytes of the offsets as hex and printable ASCII highlighting those differing from the other bytes
+struct HexdumpRow<'a> {
+    bytes: &'a [u8],
+    other: &'a [u8],
+    offsets: std::ops::Range<usize>,
+    highlight: fn(&str) -> colored::ColoredString,
+}
+
+impl HexdumpRow<'_> {
+    /// The hex and ASCII cell of the offset
+    fn cells(&self, offset: usize) -> (String, String) {
+        let byte = self.bytes.get(offset);
+        let (hex, ascii) = match byte {
+            Some(present) if present.is_ascii_graphic() || *present == b' ' => {
+                (format!("{present:02x}"), char::from(*present).to_string())
+            }
+            Some(present) => (format!("{present:02x}"), String::from(".")),
+            // missing bytes are only marked if the other side has a byte there
+            None if self.other.get(offset).is_some() => (String::from("--"), String::from(" ")),
+            None => (String::from("  "), String::from(" ")),
+        };
+
+        if byte == self.other.get(offset) {
+            (hex, ascii)
+        } else {
+            (
+                (self.highlight)(&hex).to_string(),
+                (self.highlight)(&ascii).to_string(),
+            )
+        }
+    }
+}
+
+impl std::fmt::Display for HexdumpRow<'_> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        for offset in self.offsets.clone() {
+            write!(f, "{} ", self.cells(offset).0)?;
+        }
+
+        write!(f, "|")?;
+
+        for offset in self.offsets.clone() {
+            write!(f, "{}", self.cells(offset).1)?;
+        }
+
+        write!(f, "|")
+    }
+}
+
 #[track_caller]
 pub(crate) fn assert_not_equals<T>(actual: &T, expected: T)
 where
@@ -37,12 +362,62 @@ pub fn assert<Actual: Debug, Expected: Debug>(
         assertion_desc,
         Some(expected),
         None,
+        None,
+        None,
+    );
+}
+
+/// Like [`assert`] but the failure output contains a hint about the closest match if one can be found
+///
+/// The closest match is only searched for if the assertion failed.
+#[track_caller]
+pub(crate) fn assert_with_closest_match<'a, Actual: Debug, Expected: Debug>(
+    assertable: bool,
+    actual: Actual,
+    assertion_desc: &str,
+    expected: Expected,
+    find_closest_match: impl FnOnce() -> Option<ClosestMatch<'a>>,
+) {
+    let closest_match = if assertable {
+        None
+    } else {
+        find_closest_match()
+    };
+
+    assert_internal::<Actual, Expected, ()>(
+        assertable,
+        actual,
+        assertion_desc,
+        Some(expected),
+        None,
+        closest_match,
+        None,
+    );
+}
+
+/// Fails with the reason why the assertion failed instead of additional info
+#[track_caller]
+pub fn assert_with_reason<Actual: Debug, Expected: Debug>(
+    assertable: bool,
+    actual: Actual,
+    assertion_desc: &str,
+    expected: Expected,
+    reason: &str,
+) {
+    assert_internal::<Actual, Expected, ()>(
+        assertable,
+        actual,
+        assertion_desc,
+        Some(expected),
+        None,
+        None,
+        Some(reason),
     );
 }
 
 #[track_caller]
 pub fn assert_no_expected<Actual: Debug>(assertable: bool, actual: Actual, assertion_desc: &str) {
-    assert_internal::<Actual, (), ()>(assertable, actual, assertion_desc, None, None);
+    assert_internal::<Actual, (), ()>(assertable, actual, assertion_desc, None, None, None, None);
 }
 
 #[track_caller]
@@ -60,6 +435,8 @@ pub fn assert_with_additional_info<Actual: Debug, Expected: Debug, Additional: D
         assertion_desc,
         Some(expected),
         Some((additional_desc, additional)),
+        None,
+        None,
     );
 }
 
@@ -77,6 +454,8 @@ pub fn assert_with_additional_info_no_expected<Actual: Debug, Additional: Debug>
         assertion_desc,
         None::<()>,
         Some((additional_desc, additional)),
+        None,
+        None,
     );
 }
 
@@ -88,6 +467,8 @@ fn assert_internal<Actual: Debug, Expected: Debug, Additional: Debug>(
     assertion_desc: &str,
     expected: Option<Expected>,
     additional: Option<(&str, Additional)>,
+    closest_match: Option<ClosestMatch<'_>>,
+    reason: Option<&str>,
 ) {
     if assertable {
         return;
@@ -114,5 +495,18 @@ fn assert_internal<Actual: Debug, Expected: Debug, Additional: Debug>(
         message = format!("{message}\n{additional_desc}\n  {additional}");
     }
 
+    if let Some(closest_match) = closest_match {
+        message = format!(
+            "{message}\nbut the closest match with {}% similarity at byte {} is\n  {}",
+            closest_match.similarity,
+            closest_match.offset,
+            closest_match.render()
+        );
+    }
+
+    if let Some(reason) = reason {
+        message = format!("{message}\n{reason}");
+    }
+
     panic!("{message}")
 }
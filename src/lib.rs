@@ -25,14 +25,18 @@
 //! # Available Assertions
 //!
 //! 1. [Basic value assertions](#basic-value-assertions)
-//! 2. [String-likes](#string-likes)
-//! 3. [Result](#result)
-//! 4. [Option](#option)
-//! 5. [Iterables](#iterables)
-//! 6. [Filesystem / Path Assertions](#filesystem--path-assertions)
-//! 7. [File Handle Assertions](#file-handle-assertions)
-//! 8. [Json](#json)
-//! 9. [Accessors](#accessors)
+//! 2. [Integers](#integers)
+//! 3. [Durations](#durations)
+//! 4. [Points in time](#points-in-time)
+//! 5. [String-likes](#string-likes)
+//! 6. [Bytes](#bytes)
+//! 7. [Result](#result)
+//! 8. [Option](#option)
+//! 9. [Iterables](#iterables)
+//! 10. [Filesystem / Path Assertions](#filesystem--path-assertions)
+//! 11. [File Handle Assertions](#file-handle-assertions)
+//! 12. [Json](#json)
+//! 13. [Accessors](#accessors)
 //!
 //! ## Basic value assertions
 //!
@@ -103,6 +107,101 @@
 //! assert_that(true).is(true);
 //! ```
 //!
+//! ## Integers
+//!
+//! All primitive integers and their [`NonZero`](std::num::NonZero) counterparts can be asserted in sign, parity, divisibility and their bits.
+//!
+//! [All integer assertions](trait.IntegerAssertion.html)
+//!
+//! ```
+//! # use smoothy::prelude::*;
+//! assert_that(42)
+//!     .is_positive()
+//!     .and()
+//!     .is_even()
+//!     .and()
+//!     .is_divisible_by(7);
+//! assert_that(-1).is_negative();
+//! assert_that(0).is_zero();
+//! ```
+//!
+//! ```
+//! # use smoothy::prelude::*;
+//! assert_that(0x1000usize)
+//!     .is_power_of_two()
+//!     .and()
+//!     .is_aligned_to(0x100);
+//! assert_that(0b0101u8)
+//!     .has_bit_set(2)
+//!     .and()
+//!     .count_ones()
+//!     .is(2);
+//! ```
+//!
+//! ## Durations
+//!
+//! [`Duration`](std::time::Duration)s such as timeouts or backoffs can be compared to other durations.
+//! Durations are printed in a human-readable way (e.g. `1.2s`) in the failure output.
+//!
+//! [All duration assertions](trait.DurationAssertion.html)
+//!
+//! ```
+//! # use smoothy::prelude::*;
+//! # use std::time::Duration;
+//! assert_that(Duration::from_millis(210))
+//!     .is_close_to(Duration::from_millis(200))
+//!     .within(Duration::from_millis(20));
+//! ```
+//!
+//! ```
+//! # use smoothy::prelude::*;
+//! # use std::time::Duration;
+//! assert_that(Duration::from_millis(200))
+//!     .is_shorter_than(Duration::from_secs(1))
+//!     .and()
+//!     .is_longer_than(Duration::ZERO);
+//! assert_that(Duration::from_millis(200)).as_millis().is(200);
+//! ```
+//!
+//! ## Points in time
+//!
+//! [`SystemTime`](std::time::SystemTime) and [`Instant`](std::time::Instant) can be asserted relative to each other or to the current time.
+//! [`SystemTime`](std::time::SystemTime)s are printed as RFC 3339 timestamps in the failure output.
+//!
+//! [All time assertions](trait.TimeAssertion.html)
+//!
+//! ```
+//! # use smoothy::prelude::*;
+//! # use std::time::{Duration, SystemTime};
+//! let created_at = SystemTime::now();
+//!
+//! assert_that(created_at)
+//!     .is_in_the_past()
+//!     .and()
+//!     .is_within(Duration::from_secs(5))
+//!     .of(SystemTime::now());
+//! ```
+//!
+//! ```
+//! # use smoothy::prelude::*;
+//! # use std::time::{Duration, Instant};
+//! let start = Instant::now();
+//! let deadline = start + Duration::from_secs(60);
+//!
+//! assert_that(start).is_before(deadline);
+//! assert_that(deadline).is_in_the_future();
+//! assert_that(start)
+//!     .elapsed()
+//!     .is_shorter_than(Duration::from_secs(60));
+//! ```
+//!
+//! The same assertions are available for the date and time types of [chrono](https://docs.rs/chrono) and [time](https://docs.rs/time)
+//! behind the `chrono` and `time` features.
+//! These features additionally provide calendar accessors like `year()` or `weekday()`, checks whether two dates are on the same day
+//! or two date times describe the same instant across timezones, and parse string-likes into the respective types
+//! (`ChronoCalendarAssertion`, `ChronoDateTimeAssertion` and `ChronoParseAssertion` or
+//! `TimeCalendarAssertion`, `OffsetDateTimeAssertion` and `TimeParseAssertion`).
+//!
 //! ## String-likes
 //!
 //! String-likes can be asserted by calling [`contains`](trait.StringAssertion.html#tymethod.contains), [`starts_with`](trait.StringAssertion.html#tymethod.starts_with)
@@ -120,7 +219,150 @@
 //!
 //! ```
 //! # use smoothy::prelude::*;
-//! assert_that("Hello World").starts_with("Hello");
+//! assert_that("Hello World")
+//!     .starts_with("Hello")
+//!     .and()
+//!     .ends_with("World");
+//! ```
+//!
+//! When these assertions fail the closest match by edit distance is shown with the differing characters highlighted.
+//! The same hint is shown when comparing similar strings with [`equals`](trait.EqualityAssertion.html#tymethod.equals).
+//!
+//! Comparisons ignoring case use unicode case folding.
+//! With the `unicode-normalization` feature strings can also be compared in their normalized form (NFC or NFD).
+//!
+//! ```
+//! # use smoothy::prelude::*;
+//! assert_that("Content-Type")
+//!     .equals_ignoring_case("content-type")
+//!     .and()
+//!     .starts_with_ignoring_case("CONTENT")
+//!     .and()
+//!     .contains_ignoring_case("tYpE");
+//! assert_that("Straße").equals_ignoring_case("STRASSE");
+//! ```
+//!
+//! Generated text like SQL or code can be compared ignoring differences in whitespace, line endings or indentation.
+//! On failure the diff is shown on the normalized text.
+//!
+//! ```
+//! # use smoothy::prelude::*;
+//! assert_that("SELECT *\n  FROM users").equals_ignoring_whitespace("SELECT * FROM users");
+//! assert_that("first\r\nsecond").equals_ignoring_line_endings("first\nsecond");
+//! assert_that("    if true {\n        run();\n    }")
+//!     .equals_ignoring_indentation("if true {\n    run();\n}");
+//! ```
+//!
+//! Multi-line output can be asserted line by line. [`equals_lines`](trait.StringAssertion.html#tymethod.equals_lines) reports a unified diff with line numbers.
+//!
+//! ```
+//! # use smoothy::prelude::*;
+//! let output = "Compiling smoothy\nFinished release\n";
+//!
+//! assert_that(output)
+//!     .has_line_count(2)
+//!     .and()
+//!     .contains_line("Finished release")
+//!     .and()
+//!     .equals_lines("Compiling smoothy\r\nFinished release");
+//! assert_that(output)
+//!     .lines()
+//!     .first()
+//!     .equals("Compiling smoothy");
+//! ```
+//!
+//! Occurrences of substrings can be counted and their order asserted. Failures report the byte offsets of the patterns.
+//!
+//! ```
+//! # use smoothy::prelude::*;
+//! let log = "starting\nretrying\nretrying\nlistening";
+//!
+//! assert_that(log)
+//!     .contains_times("retrying", 2)
+//!     .and()
+//!     .contains_all_of(["listening", "starting"])
+//!     .and()
+//!     .contains_any_of(["ERROR", "listening"])
+//!     .and()
+//!     .contains_in_order(["starting", "retrying", "listening"]);
+//! ```
+//!
+//! Textual representations of numbers, addresses or other types can be parsed for further assertions.
+//!
+//! ```
+//! # use smoothy::prelude::*;
+//! assert_that("42").parsed::<u16>().is_divisible_by(2);
+//! ```
+//!
+//! Colored terminal output can be compared as plain text or asserted for its styles.
+//!
+//! ```
+//! # use smoothy::prelude::*;
+//! # use smoothy::{AnsiColor, AnsiStyle};
+//! let output = "\u{1b}[1;31merror\u{1b}[0m: file not found";
+//!
+//! assert_that(output)
+//!     .has_ansi_style("error", AnsiStyle::Foreground(AnsiColor::Red))
+//!     .and()
+//!     .without_ansi()
+//!     .equals("error: file not found");
+//! ```
+//!
+//! Common formats can be validated with [`StringFormatAssertion`]. Failures name the offending character and its position.
+//!
+//! ```
+//! # use smoothy::prelude::*;
+//! assert_that("67e55044-10b1-426f-9247-bb680e5fe0c8").is_uuid();
+//! assert_that("2026-10-19T13:37:00Z").is_rfc3339_timestamp();
+//! assert_that("1.2.3-rc.1").is_semver();
+//! assert_that("SGVsbG8=").is_base64();
+//! ```
+//!
+//! Hex and base64 encoded values can be decoded to assert their bytes.
+//!
+//! ```
+//! # use smoothy::prelude::*;
+//! assert_that("eyJpZCI6NDJ9")
+//!     .base64_url_decoded()
+//!     .is_valid_utf8()
+//!     .equals(r#"{"id":42}"#);
+//! assert_that("cafe").hex_decoded().equals_bytes([0xCA, 0xFE]);
+//! ```
+//!
+//! Emptiness, blankness and length can be asserted as well. Invisible characters are escaped in the failure output.
+//!
+//! ```
+//! # use smoothy::prelude::*;
+//! assert_that("").is_empty();
+//! assert_that(" \t\n").is_blank();
+//! assert_that("Grüße").length().is(5);
+//! assert_that("Grüße")
+//!     .is_not_empty()
+//!     .and()
+//!     .has_length(5)
+//!     .and()
+//!     .byte_length()
+//!     .is(7);
+//! ```
+//!
+//! Generated parts of a value like IDs or timestamps can be matched by the placeholders of a template.
+//!
+//! ```
+//! # use smoothy::prelude::*;
+//! assert_that("Created order 7f3a9c at 2026-10-19T13:37:00Z")
+//!     .matches_template("Created order {id} at {*}")
+//!     .name("id")
+//!     .equals("7f3a9c");
+//! ```
+//!
+//! Glob and wildcard patterns are available without the `regex` feature.
+//!
+//! ```
+//! # use smoothy::prelude::*;
+//! assert_that("user-alice-01.log")
+//!     .matches_glob("user-[a-z]*-[0-9][0-9].log")
+//!     .and()
+//!     .matches_wildcard("user-*-??.log");
 //! ```
 //!
 //! ```
@@ -129,6 +371,37 @@
 //! assert_that("Hello World").matches(&Regex::new(r"\bHello\b").unwrap());
 //! ```
 //!
+//! Patterns can also be compiled on the fly and values extracted by capture groups can be asserted further.
+//!
+//! ```
+//! # use smoothy::prelude::*;
+//! # use regex::Regex;
+//! assert_that("created user-42").matches_pattern(r"user-\d+");
+//! assert_that("created user-42")
+//!     .captures(&Regex::new(r"user-(?<id>\d+)").unwrap())
+//!     .name("id")
+//!     .equals("42");
+//! ```
+//!
+//! ## Bytes
+//!
+//! Byte slices and vectors are rendered as hex in the failure output.
+//! Comparing them with [`equals_bytes`](trait.BytesAssertion.html#tymethod.equals_bytes) shows a side-by-side hexdump of the differing rows.
+//!
+//! [All bytes assertions](trait.BytesAssertion.html)
+//!
+//! ```
+//! # use smoothy::prelude::*;
+//! let png = vec![0x89, 0x50, 0x4E, 0x47, 0x0D, 0x0A, 0x1A, 0x0A];
+//!
+//! assert_that(png.clone())
+//!     .starts_with_bytes(b"\x89PNG")
+//!     .and()
+//!     .equals_bytes(b"\x89PNG\r\n\x1a\n");
+//! assert_that(png).as_hex().equals("89504e470d0a1a0a");
+//! assert_that(b"Hello").is_valid_utf8().equals("Hello");
+//! ```
+//!
 //! ## Result
 //!
 //! Results can be asserted by calling [`is_err`](trait.ResultAssertion.html#tymethod.is_err) or [`is_ok`](trait.ResultAssertion.html#tymethod.is_ok).
@@ -256,6 +529,30 @@
 //! assert_that([1, 2, 3]).second().is(2);
 //! assert_that([1, 2, 3]).third().is(3);
 //! assert_that([1, 2, 3]).nth(0).is(1);
+//! assert_that([1, 2, 3]).last().is(3);
+//! assert_that([1, 2, 3]).nth_from_end(1).is(2);
+//! assert_that([42]).single().is(42);
+//! ```
+//!
+//! ### Aggregates
+//!
+//! Iterables can be reduced to a single value which can then be asserted further.
+//! Accessing any aggregate of an empty iterable fails the assertion, as does accessing the minimum or maximum of items or keys that cannot be compared like [`f64::NAN`].
+//!
+//! ```
+//! # use smoothy::prelude::*;
+//! let latencies = vec![120, 80, 100];
+//! assert_that(latencies.clone()).sum().is(300);
+//! assert_that(latencies.clone()).min().is(80);
+//! assert_that(latencies.clone()).max().is(120);
+//! assert_that(latencies).average().is(100.0);
+//! ```
+//!
+//! ```
+//! # use smoothy::prelude::*;
+//! assert_that(["Hello", "World", "!"])
+//!     .max_by_key(|word| word.len())
+//!     .is("World");
 //! ```
 //!
 //! ### Content assertions
@@ -272,9 +569,9 @@
 //! | [`contains_only`](trait.IteratorAssertion.html#tymethod.contains_only)              | false   | false       | true      |                     |
 //! | -                          | false   | true        | false     | Does not make sense |
 //! | -                          | false   | true        | true      | Does not make sense |
-//! | `contains_in_order` (WIP)    | true    | false       | false     |                     |
+//! | [`contains_in_order`](trait.IteratorAssertion.html#tymethod.contains_in_order)          | true    | false       | false     |                     |
 //! | -                | true    | false       | true      | Could be useful, but is it needed?                    |
-//! | `contains_in_sequence` (WIP) | true    | true        | false     |                     |
+//! | [`contains_in_sequence`](trait.IteratorAssertion.html#tymethod.contains_in_sequence)       | true    | true        | false     |                     |
 //! | [`is`](trait.EqualityAssertion.html#tymethod.is) / [`equals`](trait.EqualityAssertion.html#tymethod.equals)                | true    | true        | true      |                     |
 //!
 //! ```
@@ -283,6 +580,8 @@
 //! assert_that([1, 2, 3]).contains(1);
 //! assert_that([1, 2, 3]).contains_all([2, 1]);
 //! assert_that([1, 2, 3, 2]).contains_only([3, 1, 2, 2]);
+//! assert_that([1, 2, 3, 4]).contains_in_order([1, 3, 4]);
+//! assert_that([1, 2, 3, 4]).contains_in_sequence([2, 3]);
 //! ```
 //!
 //! ### Predicate-based assertions
@@ -308,6 +607,50 @@
 //! assert_that(numbers).none_match(|x| x % 2 == 0);
 //! ```
 //!
+//! ### Order
+//!
+//! The order of the items can be asserted as well. Failures report the first pair of adjacent items violating the order.
+//!
+//! ```
+//! # use smoothy::prelude::*;
+//! assert_that([1, 2, 2, 3]).is_sorted();
+//! assert_that([3, 2, 1]).is_sorted_descending();
+//! assert_that([1, 2, 3]).is_strictly_sorted();
+//! assert_that(["a", "bb", "ccc"]).is_sorted_by_key(|word| word.len());
+//! ```
+//!
+//! ### Uniqueness
+//!
+//! Duplicates are reported with all indices they occur at.
+//! Items are compared pairwise, hashable items can be checked in linear time with the `_by_hash` variants instead.
+//!
+//! ```
+//! # use smoothy::prelude::*;
+//! assert_that([1, 2, 3]).has_unique_elements();
+//! assert_that(0..10_000).has_unique_elements_by_hash();
+//! assert_that([(1, "alice"), (2, "bob")]).has_unique_elements_by_key(|(id, _name)| *id);
+//! assert_that([1, 2, 1, 3, 2]).duplicates().is(vec![1, 2]);
+//! ```
+//!
+//! ### Transformations
+//!
+//! Iterables can be transformed before asserting their content further.
+//!
+//! ```
+//! # use smoothy::prelude::*;
+//! let users = vec![("Alice", 32), ("Bob", 27), ("Carol", 45)];
+//!
+//! assert_that(users)
+//!     .filtered(|(_name, age)| *age > 30)
+//!     .extracting(|(name, _age)| name)
+//!     .is(vec!["Alice", "Carol"]);
+//! assert_that(vec![vec![3], vec![1, 2]])
+//!     .flattened()
+//!     .sorted()
+//!     .reversed()
+//!     .is(vec![3, 2, 1]);
+//! ```
+//!
 //! ## Filesystem / Path Assertions
 //!
 //! Path assertions work with any type implementing [`AsRef<Path>`](std::path::Path) such as [`str`], [`String`], [`&Path`](std::path::Path), and [`PathBuf`](std::path::PathBuf).
@@ -443,29 +786,54 @@ mod accessors;
 mod assertions;
 mod implementation;
 
+#[cfg_attr(docsrs, doc(cfg(feature = "chrono")))]
+#[cfg(feature = "chrono")]
+pub use assertions::chrono::{
+    ChronoCalendarAssertion, ChronoDateTimeAssertion, ChronoParseAssertion,
+};
 #[cfg_attr(docsrs, doc(cfg(feature = "json")))]
 #[cfg(feature = "json")]
 pub use assertions::json::{JsonObjectAssertion, JsonValueAssertion};
+#[cfg_attr(docsrs, doc(cfg(feature = "time")))]
+#[cfg(feature = "time")]
+pub use assertions::time_crate::{
+    CalendarDate, OffsetDateTimeAssertion, TimeCalendarAssertion, TimeParseAssertion,
+};
 pub use assertions::{
+    ansi::{AnsiColor, AnsiStyle},
     boolean::BooleanAssertion,
+    bytes::BytesAssertion,
+    captures::{Captures, CapturesAssertion},
+    duration::{CloseToAsserter, DurationAssertion},
     equality::EqualityAssertion,
     file::FileAssertion,
-    iter::IteratorAssertion,
+    format::StringFormatAssertion,
+    integer::IntegerAssertion,
+    iter::{IntoF64, IteratorAssertion},
     option::{OptionAssertion, SomeAsserter},
     path::PathAssertion,
     result::{ErrAsserter, OkAsserter, ResultAssertion},
     string::StringAssertion,
+    time::{TimeAssertion, Timestamp, WithinAsserter},
 };
 
 /// The prelude for smoothy. Contains the most important structs, traits and functions but not all
 pub mod prelude {
     pub use crate::{
-        assert_that, Asserter, BooleanAssertion, EqualityAssertion, FileAssertion,
-        IteratorAssertion, OptionAssertion, PathAssertion, ResultAssertion, StringAssertion,
+        assert_that, Asserter, BooleanAssertion, BytesAssertion, CapturesAssertion,
+        DurationAssertion, EqualityAssertion, FileAssertion, IntegerAssertion, IteratorAssertion,
+        OptionAssertion, PathAssertion, ResultAssertion, StringAssertion, StringFormatAssertion,
+        TimeAssertion,
     };
+    #[cfg_attr(docsrs, doc(cfg(feature = "chrono")))]
+    #[cfg(feature = "chrono")]
+    pub use crate::{ChronoCalendarAssertion, ChronoDateTimeAssertion, ChronoParseAssertion};
     #[cfg_attr(docsrs, doc(cfg(feature = "json")))]
     #[cfg(feature = "json")]
     pub use crate::{JsonObjectAssertion, JsonValueAssertion};
+    #[cfg_attr(docsrs, doc(cfg(feature = "time")))]
+    #[cfg(feature = "time")]
+    pub use crate::{OffsetDateTimeAssertion, TimeCalendarAssertion, TimeParseAssertion};
 }
 
 /// Entrypoint for all assertions
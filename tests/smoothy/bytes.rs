@@ -0,0 +1,142 @@
+use crate::failing_assertion;
+use smoothy::{assert_that, BytesAssertion, EqualityAssertion, StringAssertion};
+
+mod equals_bytes {
+    use super::*;
+
+    #[test]
+    fn succeeds() {
+        assert_that(vec![0xCA, 0xFE, 0xBA, 0xBE]).equals_bytes([0xCA, 0xFE, 0xBA, 0xBE]);
+        assert_that(b"Hello".as_slice()).equals_bytes("Hello");
+        assert_that(Vec::<u8>::new()).equals_bytes([]);
+    }
+
+    #[test]
+    fn fails_with_differing_byte() {
+        failing_assertion!({
+            assert_that(b"Hello World".to_vec()).equals_bytes(b"Hello world");
+        });
+    }
+
+    #[test]
+    fn fails_with_different_length() {
+        failing_assertion!({
+            assert_that(vec![
+                0x00, 0x01, 0x02, 0x03, 0x04, 0x05, 0x06, 0x07, 0x08, 0x09,
+            ])
+            .equals_bytes([0x00, 0x01, 0x02, 0x03, 0x04, 0x05, 0x06, 0x07]);
+        });
+    }
+
+    #[test]
+    fn fails_and_skips_equal_rows() {
+        let expected: Vec<u8> = (0..=63).collect();
+        let mut actual = expected.clone();
+        if let Some(byte) = actual.get_mut(3) {
+            *byte = 0xFF;
+        }
+        if let Some(byte) = actual.get_mut(50) {
+            *byte = b'A';
+        }
+
+        failing_assertion!({
+            assert_that(actual.clone()).equals_bytes(expected.clone());
+        });
+    }
+}
+
+mod starts_with_bytes {
+    use super::*;
+
+    #[test]
+    fn succeeds() {
+        assert_that(b"\x89PNG\r\n\x1a\n").starts_with_bytes(b"\x89PNG");
+        assert_that(vec![0x01, 0x02]).starts_with_bytes([]);
+    }
+
+    #[test]
+    fn fails() {
+        failing_assertion!({
+            assert_that(b"GIF89a").starts_with_bytes(b"\x89PNG");
+        });
+    }
+}
+
+mod ends_with_bytes {
+    use super::*;
+
+    #[test]
+    fn succeeds() {
+        assert_that(vec![0x01, 0x02, 0x0D, 0x0A]).ends_with_bytes(b"\r\n");
+    }
+
+    #[test]
+    fn fails() {
+        failing_assertion!({
+            assert_that(vec![0x01, 0x02, 0x0A]).ends_with_bytes(b"\r\n");
+        });
+    }
+}
+
+mod contains_bytes {
+    use super::*;
+
+    #[test]
+    fn succeeds() {
+        assert_that(vec![0x00, 0xDE, 0xAD, 0xBE, 0xEF, 0x00]).contains_bytes([0xBE, 0xEF]);
+        assert_that(vec![0x00]).contains_bytes([]);
+    }
+
+    #[test]
+    fn fails() {
+        failing_assertion!({
+            assert_that(vec![0x00, 0xDE, 0xAD, 0xBE, 0xEF, 0x00]).contains_bytes([0xEF, 0xBE]);
+        });
+    }
+
+    #[test]
+    fn fails_when_longer_than_value() {
+        failing_assertion!({
+            assert_that(vec![0xBE]).contains_bytes([0xBE, 0xEF]);
+        });
+    }
+}
+
+mod is_valid_utf8 {
+    use super::*;
+
+    #[test]
+    fn succeeds() {
+        assert_that(vec![0x47, 0x72, 0xC3, 0xBC, 0xC3, 0x9F, 0x65])
+            .is_valid_utf8()
+            .equals("Grüße")
+            .and()
+            .has_length(5);
+    }
+
+    #[test]
+    fn fails_with_invalid_byte() {
+        failing_assertion!({
+            let _string = assert_that(vec![0x47, 0xFF, 0x65]).is_valid_utf8();
+        });
+    }
+
+    #[test]
+    fn fails_with_incomplete_sequence() {
+        failing_assertion!({
+            let _string = assert_that(vec![0x47, 0x72, 0xC3]).is_valid_utf8();
+        });
+    }
+}
+
+mod as_hex {
+    use super::*;
+
+    #[test]
+    fn succeeds() {
+        assert_that(vec![0xCA, 0xFE, 0x00, 0x0F])
+            .as_hex()
+            .equals("cafe000f");
+        assert_that(Vec::<u8>::new()).as_hex().is_empty();
+    }
+}
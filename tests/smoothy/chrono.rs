@@ -0,0 +1,158 @@
+use crate::failing_assertion;
+use chrono::{DateTime, FixedOffset, NaiveDate, TimeZone, Utc, Weekday};
+use smoothy::{
+    assert_that, ChronoCalendarAssertion, ChronoDateTimeAssertion, ChronoParseAssertion,
+    DurationAssertion, EqualityAssertion, TimeAssertion,
+};
+use std::time::Duration;
+
+#[allow(clippy::unwrap_used)]
+fn date(year: i32, month: u32, day: u32) -> NaiveDate {
+    NaiveDate::from_ymd_opt(year, month, day).unwrap()
+}
+
+#[allow(clippy::unwrap_used)]
+fn utc(day: u32, hour: u32, minute: u32) -> DateTime<Utc> {
+    Utc.with_ymd_and_hms(2026, 10, day, hour, minute, 0)
+        .unwrap()
+}
+
+#[allow(clippy::unwrap_used)]
+fn cest() -> FixedOffset {
+    FixedOffset::east_opt(2 * 3_600).unwrap()
+}
+
+mod time_assertions {
+    use super::*;
+
+    #[test]
+    fn succeed_with_date_time() {
+        let noon = utc(19, 12, 0);
+
+        assert_that(noon)
+            .is_before(noon + chrono::Duration::seconds(1))
+            .and()
+            .is_after(noon - chrono::Duration::seconds(1))
+            .and()
+            .is_between(noon, noon)
+            .and()
+            .is_within(Duration::from_secs(60))
+            .of(noon + chrono::Duration::seconds(60));
+    }
+
+    #[test]
+    fn succeed_with_naive_date() {
+        assert_that(date(2026, 10, 19))
+            .is_after(date(2026, 10, 18))
+            .and()
+            .is_within(Duration::from_secs(86_400))
+            .of(date(2026, 10, 20));
+        assert_that(date(2000, 1, 1)).is_in_the_past();
+    }
+
+    #[test]
+    fn succeed_relative_to_now() {
+        assert_that(Utc::now() - chrono::Duration::seconds(60))
+            .elapsed()
+            .is_longer_than(Duration::from_secs(59));
+        assert_that(Utc::now().naive_utc() + chrono::Duration::seconds(60)).is_in_the_future();
+    }
+
+    #[test]
+    fn fails() {
+        failing_assertion!({
+            assert_that(utc(19, 12, 0)).is_before(utc(19, 11, 0));
+        });
+    }
+}
+
+mod calendar {
+    use super::*;
+
+    #[test]
+    fn accessors() {
+        let date_time = utc(19, 23, 30);
+
+        assert_that(date_time).year().is(2026);
+        assert_that(date_time).month().is(10);
+        assert_that(date_time).day().is(19);
+        assert_that(date_time).weekday().is(Weekday::Mon);
+    }
+
+    #[test]
+    fn accessors_use_the_timezone_of_the_date_time() {
+        let date_time = utc(19, 23, 30).with_timezone(&cest());
+
+        assert_that(date_time).day().is(20);
+        assert_that(date_time).weekday().is(Weekday::Tue);
+    }
+
+    #[test]
+    fn is_same_day_as_succeeds() {
+        assert_that(utc(19, 23, 59)).is_same_day_as(date(2026, 10, 19));
+    }
+
+    #[test]
+    fn is_same_day_as_fails() {
+        failing_assertion!({
+            assert_that(date(2026, 10, 19)).is_same_day_as(date(2025, 10, 19));
+        });
+    }
+}
+
+mod is_same_instant_as {
+    use super::*;
+
+    #[test]
+    fn succeeds() {
+        let utc = utc(19, 12, 0);
+
+        assert_that(utc).is_same_instant_as(utc.with_timezone(&cest()));
+    }
+
+    #[test]
+    fn fails() {
+        failing_assertion!({
+            assert_that(utc(19, 12, 0)).is_same_instant_as(utc(19, 10, 0).with_timezone(&cest()));
+        });
+    }
+}
+
+mod parsing {
+    use super::*;
+
+    #[test]
+    fn to_chrono_date_time_succeeds() {
+        assert_that("2026-10-19T14:00:00+02:00")
+            .to_chrono_date_time()
+            .is_same_instant_as(utc(19, 12, 0));
+    }
+
+    #[test]
+    fn to_chrono_date_time_fails() {
+        failing_assertion!({
+            let _parsed = assert_that("2026-10-19 14:00").to_chrono_date_time();
+        });
+    }
+
+    #[test]
+    fn to_chrono_naive_date_succeeds() {
+        assert_that(String::from("19.10.2026"))
+            .to_chrono_naive_date("%d.%m.%Y")
+            .equals(date(2026, 10, 19));
+    }
+
+    #[test]
+    fn to_chrono_naive_date_fails() {
+        failing_assertion!({
+            let _parsed = assert_that("2026-10-19").to_chrono_naive_date("%d.%m.%Y");
+        });
+    }
+
+    #[test]
+    fn to_chrono_naive_date_time_succeeds() {
+        assert_that("2026-10-19 13:37")
+            .to_chrono_naive_date_time("%Y-%m-%d %H:%M")
+            .is_same_day_as(date(2026, 10, 19));
+    }
+}
@@ -0,0 +1,107 @@
+use crate::failing_assertion;
+use smoothy::{assert_that, DurationAssertion, EqualityAssertion, IntegerAssertion};
+use std::time::Duration;
+
+mod is_close_to {
+    use super::*;
+
+    #[test]
+    fn succeeds_when_longer() {
+        assert_that(Duration::from_millis(210))
+            .is_close_to(Duration::from_millis(200))
+            .within(Duration::from_millis(20));
+    }
+
+    #[test]
+    fn succeeds_when_shorter() {
+        assert_that(Duration::from_millis(180))
+            .is_close_to(Duration::from_millis(200))
+            .within(Duration::from_millis(20));
+    }
+
+    #[test]
+    fn can_be_chained() {
+        assert_that(Duration::from_secs(1))
+            .is_close_to(Duration::from_secs(1))
+            .within(Duration::ZERO)
+            .and()
+            .is_longer_than(Duration::from_millis(999));
+    }
+
+    #[test]
+    fn fails() {
+        failing_assertion!({
+            assert_that(Duration::from_millis(1_200))
+                .is_close_to(Duration::from_millis(200))
+                .within(Duration::from_millis(20));
+        });
+    }
+}
+
+mod is_shorter_than {
+    use super::*;
+
+    #[test]
+    fn succeeds() {
+        assert_that(Duration::from_millis(200)).is_shorter_than(Duration::from_secs(1));
+    }
+
+    #[test]
+    fn fails_when_equal() {
+        failing_assertion!({
+            assert_that(Duration::from_secs(1)).is_shorter_than(Duration::from_secs(1));
+        });
+    }
+}
+
+mod is_longer_than {
+    use super::*;
+
+    #[test]
+    fn succeeds() {
+        assert_that(Duration::from_secs(90)).is_longer_than(Duration::from_secs(60));
+    }
+
+    #[test]
+    fn fails() {
+        failing_assertion!({
+            assert_that(Duration::from_micros(1_500)).is_longer_than(Duration::from_secs(2));
+        });
+    }
+}
+
+mod is_zero {
+    use super::*;
+
+    #[test]
+    fn succeeds() {
+        assert_that(Duration::ZERO).is_zero();
+    }
+
+    #[test]
+    fn fails() {
+        failing_assertion!({
+            assert_that(Duration::from_nanos(1)).is_zero();
+        });
+    }
+}
+
+mod accessors {
+    use super::*;
+
+    #[test]
+    fn as_millis() {
+        assert_that(Duration::from_micros(1_500))
+            .as_millis()
+            .is(1)
+            .and()
+            .is_odd();
+    }
+
+    #[test]
+    fn as_secs_f64() {
+        assert_that(Duration::from_millis(250))
+            .as_secs_f64()
+            .is(0.25);
+    }
+}
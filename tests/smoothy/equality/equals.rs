@@ -493,6 +493,44 @@ mod with_chars {
     }
 }
 
+mod with_strings {
+    use super::*;
+
+    #[test]
+    fn succeeds() {
+        assert_that("Hello").equals("Hello");
+        assert_that(String::from("Hello")).equals("Hello");
+    }
+
+    #[test]
+    fn fails_with_closest_match() {
+        failing_assertion!({
+            assert_that("Connection refused").equals("connection refused");
+        });
+    }
+
+    #[test]
+    fn fails_with_closest_match_of_escaped_characters() {
+        failing_assertion!({
+            assert_that(String::from("tab\tseparated \"value\"")).equals("tab separated \"value\"");
+        });
+    }
+
+    #[test]
+    fn fails_with_diff_when_not_similar() {
+        failing_assertion!({
+            assert_that("Hello There").equals("yo");
+        });
+    }
+
+    #[test]
+    fn fails_with_closest_match_across_lines() {
+        failing_assertion!({
+            assert_that("first\nsecond").equals("first\nsecont");
+        });
+    }
+}
+
 mod with_bools {
     use super::*;
 
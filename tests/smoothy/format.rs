@@ -0,0 +1,511 @@
+use crate::failing_assertion;
+use smoothy::{assert_that, BytesAssertion, EqualityAssertion, StringFormatAssertion};
+
+mod uuid {
+    use super::*;
+
+    #[test]
+    fn succeeds() {
+        assert_that("67e55044-10b1-426f-9247-bb680e5fe0c8")
+            .is_uuid()
+            .and()
+            .is_lowercase();
+        assert_that("67E55044-10B1-426F-9247-BB680E5FE0C8".to_string()).is_uuid();
+    }
+
+    #[test]
+    fn fails_with_invalid_character() {
+        failing_assertion!({
+            assert_that("67e55044-10b1-426f-9247-bb680e5fe0cg").is_uuid();
+        });
+    }
+
+    #[test]
+    fn fails_without_hyphens() {
+        failing_assertion!({
+            assert_that("67e5504410b1426f9247bb680e5fe0c8").is_uuid();
+        });
+    }
+
+    #[test]
+    fn fails_when_too_long() {
+        failing_assertion!({
+            assert_that("67e55044-10b1-426f-9247-bb680e5fe0c8a").is_uuid();
+        });
+    }
+}
+
+mod email {
+    use super::*;
+
+    #[test]
+    fn succeeds() {
+        assert_that("jane.doe+test@example.com").is_email();
+        assert_that("admin@localhost").is_email();
+        assert_that("x@sub-domain.example.co").is_email();
+    }
+
+    #[test]
+    fn fails_with_whitespace() {
+        failing_assertion!({
+            assert_that("jane doe@example.com").is_email();
+        });
+    }
+
+    #[test]
+    fn fails_without_local_part() {
+        failing_assertion!({
+            assert_that("@example.com").is_email();
+        });
+    }
+
+    #[test]
+    fn fails_with_invalid_domain() {
+        failing_assertion!({
+            assert_that("jane@-example.com").is_email();
+        });
+    }
+
+    #[test]
+    fn fails_with_trailing_dot() {
+        failing_assertion!({
+            assert_that("jane@example.").is_email();
+        });
+    }
+
+    #[test]
+    fn fails_with_leading_dot_in_local_part() {
+        failing_assertion!({
+            assert_that(".a@b.c").is_email();
+        });
+    }
+
+    #[test]
+    fn fails_with_trailing_dot_in_local_part() {
+        failing_assertion!({
+            assert_that("a.@b.c").is_email();
+        });
+    }
+
+    #[test]
+    fn fails_with_consecutive_dots_in_local_part() {
+        failing_assertion!({
+            assert_that("a..b@b.c").is_email();
+        });
+    }
+}
+
+mod url {
+    use super::*;
+
+    #[test]
+    fn succeeds() {
+        assert_that("https://example.com/search?q=smoothy#results").is_url();
+        assert_that("postgres+tls://user:secret@db:5432").is_url();
+    }
+
+    #[test]
+    fn fails_without_scheme() {
+        failing_assertion!({
+            assert_that("example.com/search").is_url();
+        });
+    }
+
+    #[test]
+    fn fails_without_host() {
+        failing_assertion!({
+            assert_that("file:///etc/hosts").is_url();
+        });
+    }
+
+    #[test]
+    fn fails_with_whitespace() {
+        failing_assertion!({
+            assert_that("https://example.com/search?q=hello world").is_url();
+        });
+    }
+}
+
+mod ipv4 {
+    use super::*;
+
+    #[test]
+    fn succeeds() {
+        assert_that("192.168.0.1").is_ipv4();
+        assert_that("0.0.0.0").is_ipv4();
+        assert_that("255.255.255.255").is_ipv4();
+    }
+
+    #[test]
+    fn fails_with_octet_out_of_range() {
+        failing_assertion!({
+            assert_that("192.168.0.256").is_ipv4();
+        });
+    }
+
+    #[test]
+    fn fails_with_leading_zero() {
+        failing_assertion!({
+            assert_that("192.168.00.1").is_ipv4();
+        });
+    }
+
+    #[test]
+    fn fails_with_missing_octet() {
+        failing_assertion!({
+            assert_that("192.168.0").is_ipv4();
+        });
+    }
+}
+
+mod ipv6 {
+    use super::*;
+
+    #[test]
+    fn succeeds() {
+        assert_that("2001:0db8:85a3:0000:0000:8a2e:0370:7334").is_ipv6();
+        assert_that("2001:db8::8a2e:370:7334").is_ipv6();
+        assert_that("::1").is_ipv6();
+        assert_that("::").is_ipv6();
+        assert_that("fe80::").is_ipv6();
+        assert_that("::ffff:192.168.0.1").is_ipv6();
+    }
+
+    #[test]
+    fn fails_with_second_compression() {
+        failing_assertion!({
+            assert_that("2001:db8::8a2e::7334").is_ipv6();
+        });
+    }
+
+    #[test]
+    fn fails_with_too_long_group() {
+        failing_assertion!({
+            assert_that("2001:db8::8a2e3:7334").is_ipv6();
+        });
+    }
+
+    #[test]
+    fn fails_with_too_few_groups() {
+        failing_assertion!({
+            assert_that("2001:db8:8a2e:370:7334").is_ipv6();
+        });
+    }
+
+    #[test]
+    fn fails_with_trailing_colon() {
+        failing_assertion!({
+            assert_that("2001:db8::7334:").is_ipv6();
+        });
+    }
+}
+
+mod rfc3339_timestamp {
+    use super::*;
+
+    #[test]
+    fn succeeds() {
+        assert_that("2026-10-19T13:37:00Z").is_rfc3339_timestamp();
+        assert_that("2026-10-19t13:37:00.123456-02:30").is_rfc3339_timestamp();
+        assert_that("2024-02-29 23:59:60+00:00").is_rfc3339_timestamp();
+    }
+
+    #[test]
+    fn fails_with_day_out_of_range() {
+        failing_assertion!({
+            assert_that("2026-02-29T13:37:00Z").is_rfc3339_timestamp();
+        });
+    }
+
+    #[test]
+    fn fails_without_offset() {
+        failing_assertion!({
+            assert_that("2026-10-19T13:37:00").is_rfc3339_timestamp();
+        });
+    }
+
+    #[test]
+    fn fails_with_short_time() {
+        failing_assertion!({
+            assert_that("2026-10-19T13:37Z").is_rfc3339_timestamp();
+        });
+    }
+}
+
+mod semver {
+    use super::*;
+
+    #[test]
+    fn succeeds() {
+        assert_that("1.2.3").is_semver();
+        assert_that("0.0.0-alpha.0.x-y+build.001").is_semver();
+        assert_that("10.20.30+meta").is_semver();
+    }
+
+    #[test]
+    fn fails_with_leading_zero() {
+        failing_assertion!({
+            assert_that("1.02.3").is_semver();
+        });
+    }
+
+    #[test]
+    fn fails_with_missing_patch() {
+        failing_assertion!({
+            assert_that("1.2").is_semver();
+        });
+    }
+
+    #[test]
+    fn fails_with_empty_pre_release_identifier() {
+        failing_assertion!({
+            assert_that("1.2.3-rc..1").is_semver();
+        });
+    }
+}
+
+mod hex {
+    use super::*;
+
+    #[test]
+    fn succeeds() {
+        assert_that("deadBEEF42").is_hex();
+    }
+
+    #[test]
+    fn fails_with_prefix() {
+        failing_assertion!({
+            assert_that("0xdeadbeef").is_hex();
+        });
+    }
+
+    #[test]
+    fn fails_when_empty() {
+        failing_assertion!({
+            assert_that("").is_hex();
+        });
+    }
+}
+
+mod base64 {
+    use super::*;
+
+    #[test]
+    fn succeeds() {
+        assert_that("SGVsbG8gV29ybGQ=").is_base64();
+        assert_that("SGk=").is_base64();
+        assert_that("SA==").is_base64();
+        assert_that("a+/9").is_base64();
+    }
+
+    #[test]
+    fn fails_without_padding() {
+        failing_assertion!({
+            assert_that("SGVsbG8gV29ybGQ").is_base64();
+        });
+    }
+
+    #[test]
+    fn fails_with_url_safe_alphabet() {
+        failing_assertion!({
+            assert_that("a-_9").is_base64();
+        });
+    }
+
+    #[test]
+    fn fails_with_data_after_padding() {
+        failing_assertion!({
+            assert_that("SG=k").is_base64();
+        });
+    }
+}
+
+mod hex_decoded {
+    use super::*;
+
+    #[test]
+    fn succeeds() {
+        assert_that("CAFEbabe")
+            .hex_decoded()
+            .equals_bytes([0xCA, 0xFE, 0xBA, 0xBE]);
+        assert_that("00ff".to_string())
+            .hex_decoded()
+            .is(vec![0x00, 0xFF]);
+    }
+
+    #[test]
+    fn fails_with_invalid_character() {
+        failing_assertion!({
+            let _bytes = assert_that("cafe babe").hex_decoded();
+        });
+    }
+
+    #[test]
+    fn fails_with_odd_length() {
+        failing_assertion!({
+            let _bytes = assert_that("cafe0").hex_decoded();
+        });
+    }
+}
+
+mod base64_decoded {
+    use super::*;
+
+    #[test]
+    fn succeeds() {
+        assert_that("SGVsbG8gV29ybGQ=")
+            .base64_decoded()
+            .is_valid_utf8()
+            .equals("Hello World");
+        assert_that("SA==").base64_decoded().is(vec![b'H']);
+        assert_that("SGk=").base64_decoded().is(b"Hi".to_vec());
+        assert_that("+/+/")
+            .base64_decoded()
+            .is(vec![0xFB, 0xFF, 0xBF]);
+    }
+
+    #[test]
+    fn fails_with_url_safe_alphabet() {
+        failing_assertion!({
+            let _bytes = assert_that("SGVsbG8g-29ybGQ=").base64_decoded();
+        });
+    }
+
+    #[test]
+    fn fails_without_padding() {
+        failing_assertion!({
+            let _bytes = assert_that("SGk").base64_decoded();
+        });
+    }
+}
+
+mod base64_url_decoded {
+    use super::*;
+
+    #[test]
+    fn succeeds() {
+        assert_that("eyJhbGciOiJIUzI1NiJ9")
+            .base64_url_decoded()
+            .is_valid_utf8()
+            .equals(r#"{"alg":"HS256"}"#);
+        assert_that("SGk").base64_url_decoded().is(b"Hi".to_vec());
+        assert_that("SGk=").base64_url_decoded().is(b"Hi".to_vec());
+        assert_that("-_-_")
+            .base64_url_decoded()
+            .is(vec![0xFB, 0xFF, 0xBF]);
+    }
+
+    #[test]
+    fn fails_with_standard_alphabet() {
+        failing_assertion!({
+            let _bytes = assert_that("a+/9").base64_url_decoded();
+        });
+    }
+
+    #[test]
+    fn fails_with_single_trailing_character() {
+        failing_assertion!({
+            let _bytes = assert_that("SGVsb").base64_url_decoded();
+        });
+    }
+}
+
+#[cfg(feature = "json")]
+mod json {
+    use super::*;
+
+    #[test]
+    fn succeeds() {
+        assert_that(r#"{"id": 42, "tags": []}"#).is_json();
+        assert_that("null").is_json();
+    }
+
+    #[test]
+    fn fails() {
+        failing_assertion!({
+            assert_that(r#"{"id": 42,}"#).is_json();
+        });
+    }
+}
+
+mod character_classes {
+    use super::*;
+
+    #[test]
+    fn succeeds() {
+        assert_that("Hello World!").is_ascii();
+        assert_that("Grüße42").is_alphanumeric();
+        assert_that("HTTP/2 OK").is_uppercase();
+        assert_that("user-42@example.com").is_lowercase();
+    }
+
+    #[test]
+    fn ascii_fails_when_empty() {
+        failing_assertion!({
+            assert_that("").is_ascii();
+        });
+    }
+
+    #[test]
+    fn alphanumeric_fails_when_empty() {
+        failing_assertion!({
+            assert_that("").is_alphanumeric();
+        });
+    }
+
+    #[test]
+    fn uppercase_fails_when_empty() {
+        failing_assertion!({
+            assert_that("").is_uppercase();
+        });
+    }
+
+    #[test]
+    fn uppercase_fails_without_letters() {
+        failing_assertion!({
+            assert_that("123").is_uppercase();
+        });
+    }
+
+    #[test]
+    fn lowercase_fails_when_empty() {
+        failing_assertion!({
+            assert_that("").is_lowercase();
+        });
+    }
+
+    #[test]
+    fn lowercase_fails_without_letters() {
+        failing_assertion!({
+            assert_that("123").is_lowercase();
+        });
+    }
+
+    #[test]
+    fn ascii_fails() {
+        failing_assertion!({
+            assert_that("Grüße").is_ascii();
+        });
+    }
+
+    #[test]
+    fn alphanumeric_fails() {
+        failing_assertion!({
+            assert_that("Hello World").is_alphanumeric();
+        });
+    }
+
+    #[test]
+    fn uppercase_fails() {
+        failing_assertion!({
+            assert_that("HTTP/2 Ok").is_uppercase();
+        });
+    }
+
+    #[test]
+    fn lowercase_fails() {
+        failing_assertion!({
+            assert_that("User-42@example.com").is_lowercase();
+        });
+    }
+}
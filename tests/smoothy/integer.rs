@@ -0,0 +1,217 @@
+use crate::failing_assertion;
+use smoothy::{assert_that, EqualityAssertion, IntegerAssertion};
+use std::num::{NonZeroI32, NonZeroU64};
+
+mod sign {
+    use super::*;
+
+    #[test]
+    fn is_positive_succeeds() {
+        assert_that(42).is_positive();
+        assert_that(1u8).is_positive();
+        assert_that(i128::MAX).is_positive();
+    }
+
+    #[test]
+    fn is_positive_fails_for_zero() {
+        failing_assertion!({
+            assert_that(0u16).is_positive();
+        });
+    }
+
+    #[test]
+    fn is_negative_succeeds() {
+        assert_that(-42).is_negative();
+        assert_that(i8::MIN).is_negative();
+    }
+
+    #[test]
+    fn is_negative_fails_for_unsigned() {
+        failing_assertion!({
+            assert_that(42u32).is_negative();
+        });
+    }
+
+    #[test]
+    fn is_zero_succeeds() {
+        assert_that(0usize).is_zero();
+        assert_that(0i64).is_zero();
+    }
+
+    #[test]
+    fn is_zero_fails() {
+        failing_assertion!({
+            assert_that(-1).is_zero();
+        });
+    }
+
+    #[test]
+    fn can_be_chained() {
+        assert_that(-42).is_negative().and().equals(-42);
+    }
+}
+
+mod parity {
+    use super::*;
+
+    #[test]
+    fn is_even_succeeds() {
+        assert_that(0).is_even();
+        assert_that(42).is_even();
+        assert_that(-42).is_even();
+    }
+
+    #[test]
+    fn is_even_fails() {
+        failing_assertion!({
+            assert_that(41).is_even();
+        });
+    }
+
+    #[test]
+    fn is_odd_succeeds() {
+        assert_that(41).is_odd();
+        assert_that(-41).is_odd();
+    }
+
+    #[test]
+    fn is_odd_fails() {
+        failing_assertion!({
+            assert_that(42u8).is_odd();
+        });
+    }
+}
+
+mod is_divisible_by {
+    use super::*;
+
+    #[test]
+    fn succeeds() {
+        assert_that(42).is_divisible_by(7);
+        assert_that(-42).is_divisible_by(-6);
+        assert_that(0).is_divisible_by(0);
+    }
+
+    #[test]
+    fn succeeds_without_overflowing() {
+        assert_that(i32::MIN).is_divisible_by(-1);
+    }
+
+    #[test]
+    fn fails_with_remainder() {
+        failing_assertion!({
+            assert_that(42).is_divisible_by(5);
+        });
+    }
+
+    #[test]
+    fn fails_with_zero_divisor() {
+        failing_assertion!({
+            assert_that(42).is_divisible_by(0);
+        });
+    }
+}
+
+mod is_power_of_two {
+    use super::*;
+
+    #[test]
+    fn succeeds() {
+        assert_that(1u8).is_power_of_two();
+        assert_that(64).is_power_of_two();
+        assert_that(1i64 << 62).is_power_of_two();
+    }
+
+    #[test]
+    fn fails_for_negative_values() {
+        failing_assertion!({
+            assert_that(i8::MIN).is_power_of_two();
+        });
+    }
+
+    #[test]
+    fn fails() {
+        failing_assertion!({
+            assert_that(42u8).is_power_of_two();
+        });
+    }
+}
+
+mod bits {
+    use super::*;
+
+    #[test]
+    fn has_bit_set_succeeds() {
+        assert_that(0b0100u8).has_bit_set(2);
+        assert_that(-1i16).has_bit_set(15);
+    }
+
+    #[test]
+    fn has_bit_set_fails() {
+        failing_assertion!({
+            assert_that(0b0100u8).has_bit_set(1);
+        });
+    }
+
+    #[test]
+    fn has_bit_set_fails_when_out_of_range() {
+        failing_assertion!({
+            assert_that(u8::MAX).has_bit_set(8);
+        });
+    }
+
+    #[test]
+    fn count_ones() {
+        assert_that(0b1011u8).count_ones().is(3);
+        assert_that(-1i32).count_ones().is(32);
+    }
+}
+
+mod is_aligned_to {
+    use super::*;
+
+    #[test]
+    fn succeeds() {
+        assert_that(0x1000usize).is_aligned_to(0x100);
+        assert_that(0u64).is_aligned_to(8);
+    }
+
+    #[test]
+    fn fails() {
+        failing_assertion!({
+            assert_that(0x1004u32).is_aligned_to(0x100);
+        });
+    }
+
+    #[test]
+    fn fails_when_alignment_is_no_power_of_two() {
+        failing_assertion!({
+            assert_that(0x1000u32).is_aligned_to(3);
+        });
+    }
+}
+
+mod non_zero {
+    use super::*;
+
+    #[test]
+    fn succeeds() {
+        let value = NonZeroU64::new(4096).unwrap();
+
+        assert_that(value)
+            .is_positive()
+            .and()
+            .is_power_of_two()
+            .and()
+            .is_aligned_to(4096)
+            .and()
+            .is(value);
+    }
+
+    #[test]
+    fn fails() {
+        failing_assertion!({
+            assert_that(NonZeroI32::new(-3).unwrap()).is_divisible_by(2);
+        });
+    }
+}
@@ -1,10 +1,16 @@
+mod aggregates;
 mod all_match;
 mod any_match;
 mod contains;
 mod contains_all;
+mod contains_in_order;
+mod contains_in_sequence;
 mod contains_only;
 mod indices;
 mod is_empty;
 mod is_not_empty;
 mod none_match;
 mod size;
+mod sorted;
+mod transform;
+mod unique;
@@ -0,0 +1,210 @@
+use crate::failing_assertion;
+use smoothy::{assert_that, EqualityAssertion, IntegerAssertion, IteratorAssertion};
+
+mod sum {
+    use super::*;
+
+    #[test]
+    fn succeeds() {
+        assert_that(vec![10, 20, 12]).sum().is(42);
+    }
+
+    #[test]
+    fn fails_with_empty_vec() {
+        failing_assertion!({
+            assert_that::<Vec<u32>>(vec![]).sum().is_zero();
+        });
+    }
+
+    #[test]
+    fn fails() {
+        failing_assertion!({
+            assert_that([10, 20, 12]).sum().is(40);
+        });
+    }
+}
+
+mod product {
+    use super::*;
+
+    #[test]
+    fn succeeds() {
+        assert_that(vec![2, 3, 7]).product().is(42);
+    }
+
+    #[test]
+    fn fails_with_empty_vec() {
+        failing_assertion!({
+            assert_that::<Vec<u32>>(vec![]).product().is(1);
+        });
+    }
+}
+
+mod min {
+    use super::*;
+
+    #[test]
+    fn succeeds() {
+        assert_that(vec![120, 80, 95]).min().is(80);
+    }
+
+    #[test]
+    fn succeeds_with_floats() {
+        assert_that(vec![0.12, 0.08, 0.095, 0.08]).min().is(0.08);
+        assert_that(vec![f64::INFINITY, -0.5]).min().is(-0.5);
+    }
+
+    #[test]
+    fn fails_with_empty_vec() {
+        failing_assertion!({
+            assert_that::<Vec<u32>>(vec![]).min().is(80);
+        });
+    }
+
+    #[test]
+    fn fails_with_nan() {
+        failing_assertion!({
+            assert_that(vec![0.12, 0.08, f64::NAN]).min().is(0.08);
+        });
+    }
+}
+
+mod max {
+    use super::*;
+
+    #[test]
+    fn succeeds() {
+        assert_that(vec![120, 80, 95]).max().is(120).and().is_even();
+    }
+
+    #[test]
+    fn succeeds_with_floats() {
+        assert_that(vec![0.12, 0.08, 0.095]).max().is(0.12);
+    }
+
+    #[test]
+    fn fails_with_empty_vec() {
+        failing_assertion!({
+            assert_that::<Vec<u32>>(vec![]).max().is(120);
+        });
+    }
+
+    #[test]
+    fn fails_with_nan() {
+        failing_assertion!({
+            assert_that(vec![f64::NAN, 0.12]).max().is(0.12);
+        });
+    }
+}
+
+mod min_by_key {
+    use super::*;
+
+    #[test]
+    fn succeeds() {
+        assert_that(vec!["Hello", "World", "!"])
+            .min_by_key(|word| word.len())
+            .is("!");
+    }
+
+    #[test]
+    fn returns_first_of_equal_items() {
+        assert_that(vec!["Hello", "World"])
+            .min_by_key(|word| word.len())
+            .is("Hello");
+    }
+
+    #[test]
+    fn succeeds_with_float_keys() {
+        assert_that(vec![("a", 0.5), ("b", 0.25), ("c", 0.75)])
+            .min_by_key(|(_name, score)| *score)
+            .is(("b", 0.25));
+    }
+
+    #[test]
+    fn fails_with_empty_vec() {
+        failing_assertion!({
+            assert_that::<Vec<&str>>(vec![])
+                .min_by_key(|word| word.len())
+                .is("!");
+        });
+    }
+
+    #[test]
+    fn fails_with_nan_key() {
+        failing_assertion!({
+            assert_that(vec![("a", 0.5), ("b", f64::NAN), ("c", 0.75)])
+                .min_by_key(|(_name, score)| *score)
+                .is(("a", 0.5));
+        });
+    }
+}
+
+mod max_by_key {
+    use super::*;
+
+    #[test]
+    fn succeeds() {
+        assert_that(vec!["!", "Hello", "World"])
+            .max_by_key(|word| word.len())
+            .is("World");
+    }
+
+    #[test]
+    fn succeeds_with_float_keys() {
+        assert_that(vec![("a", 0.5), ("b", 0.25), ("c", 0.75)])
+            .max_by_key(|(_name, score)| *score)
+            .is(("c", 0.75));
+    }
+
+    #[test]
+    fn fails_with_empty_vec() {
+        failing_assertion!({
+            assert_that::<Vec<&str>>(vec![])
+                .max_by_key(|word| word.len())
+                .is("World");
+        });
+    }
+
+    #[test]
+    fn fails_with_nan_key() {
+        failing_assertion!({
+            assert_that(vec![("a", 0.5), ("b", f64::NAN), ("c", 0.75)])
+                .max_by_key(|(_name, score)| *score)
+                .is(("c", 0.75));
+        });
+    }
+}
+
+mod average {
+    use super::*;
+
+    #[test]
+    fn succeeds_with_floats() {
+        assert_that(vec![0.5, 1.0, 1.5]).average().is(1.0);
+    }
+
+    #[test]
+    fn succeeds_with_integers() {
+        assert_that(vec![1u8, 2]).average().is(1.5);
+    }
+
+    #[test]
+    fn succeeds_with_wide_integers() {
+        assert_that(vec![1_999_u64, 4_999, 502])
+            .average()
+            .is(2_500.0);
+        assert_that(vec![-3_i64, 5]).average().is(1.0);
+        assert_that(vec![1_usize, 2, 3]).average().is(2.0);
+        assert_that(vec![i128::MAX, i128::MAX])
+            .average()
+            .is(1.701_411_834_604_692_3e38);
+    }
+
+    #[test]
+    fn fails_with_empty_vec() {
+        failing_assertion!({
+            assert_that::<Vec<f64>>(vec![]).average().is(1.0);
+        });
+    }
+}
@@ -0,0 +1,57 @@
+use crate::failing_assertion;
+use smoothy::{assert_that, IteratorAssertion};
+
+#[test]
+fn consecutive() {
+    assert_that([1, 2, 3, 4, 5]).contains_in_order([2, 3, 4]);
+}
+
+#[test]
+fn with_items_in_between() {
+    assert_that([1, 2, 3, 4, 5]).contains_in_order([1, 3, 5]);
+}
+
+#[test]
+fn duplicates_in_expected() {
+    assert_that([1, 2, 1, 2]).contains_in_order([1, 1, 2]);
+}
+
+#[test]
+fn empty_expected() {
+    assert_that([1, 2, 3]).contains_in_order(Vec::<i32>::new());
+}
+
+#[test]
+fn expected_items_are_converted_via_into() {
+    assert_that(vec!["A".to_string(), "B".to_string(), "C".to_string()])
+        .contains_in_order(["A", "C"]);
+}
+
+#[test]
+fn can_be_chained_with_connectors() {
+    assert_that([1, 2, 3, 4, 5])
+        .contains_in_order([1, 2])
+        .and()
+        .contains_in_order([4, 5]);
+}
+
+#[test]
+fn fails_when_order_differs() {
+    failing_assertion!({
+        assert_that([1, 3, 2, 5]).contains_in_order([1, 2, 3, 5]);
+    });
+}
+
+#[test]
+fn fails_when_duplicate_is_missing() {
+    failing_assertion!({
+        assert_that([1, 2, 3]).contains_in_order([1, 2, 2]);
+    });
+}
+
+#[test]
+fn fails_when_first_item_is_missing() {
+    failing_assertion!({
+        assert_that([1, 2, 3]).contains_in_order([420, 1]);
+    });
+}
@@ -0,0 +1,61 @@
+use crate::failing_assertion;
+use smoothy::{assert_that, IteratorAssertion};
+
+#[test]
+fn at_the_start() {
+    assert_that([1, 2, 3, 4, 5]).contains_in_sequence([1, 2]);
+}
+
+#[test]
+fn in_the_middle() {
+    assert_that([1, 2, 3, 4, 5]).contains_in_sequence([2, 3, 4]);
+}
+
+#[test]
+fn at_the_end() {
+    assert_that([1, 2, 3, 4, 5]).contains_in_sequence([4, 5]);
+}
+
+#[test]
+fn after_a_partial_match() {
+    assert_that([1, 2, 1, 2, 3]).contains_in_sequence([1, 2, 3]);
+}
+
+#[test]
+fn empty_expected() {
+    assert_that([1, 2, 3]).contains_in_sequence(Vec::<i32>::new());
+}
+
+#[test]
+fn expected_items_are_converted_via_into() {
+    assert_that(vec!["A".to_string(), "B".to_string(), "C".to_string()])
+        .contains_in_sequence(["B", "C"]);
+}
+
+#[test]
+fn fails_when_items_are_in_between() {
+    failing_assertion!({
+        assert_that([1, 2, 3, 9, 4, 5]).contains_in_sequence([2, 3, 4]);
+    });
+}
+
+#[test]
+fn fails_when_actual_ends_before_sequence() {
+    failing_assertion!({
+        assert_that([1, 2, 3]).contains_in_sequence([2, 3, 4]);
+    });
+}
+
+#[test]
+fn fails_when_first_item_is_missing() {
+    failing_assertion!({
+        assert_that([1, 2, 3]).contains_in_sequence([420, 1]);
+    });
+}
+
+#[test]
+fn fails_with_the_longest_partial_window() {
+    failing_assertion!({
+        assert_that([1, 2, 7, 1, 2, 3, 7]).contains_in_sequence([1, 2, 3, 4]);
+    });
+}
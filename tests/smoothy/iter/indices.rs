@@ -81,6 +81,85 @@ mod nth {
     }
 }
 
+mod last {
+    use super::*;
+
+    #[test]
+    fn succeeds() {
+        assert_that(vec!["First", "Second", "Third"])
+            .last()
+            .equals("Third");
+    }
+
+    #[test]
+    fn fails_with_empty_vec() {
+        failing_assertion!({
+            assert_that::<Vec<&str>>(vec![])
+                .last()
+                .equals("Hello World!");
+        });
+    }
+}
+
+mod single {
+    use super::*;
+
+    #[test]
+    fn succeeds() {
+        assert_that(vec!["Hello World!"])
+            .single()
+            .equals("Hello World!");
+    }
+
+    #[test]
+    fn fails_with_empty_vec() {
+        failing_assertion!({
+            assert_that::<Vec<&str>>(vec![])
+                .single()
+                .equals("Hello World!");
+        });
+    }
+
+    #[test]
+    fn fails_with_multiple_items() {
+        failing_assertion!({
+            assert_that(vec!["Hello", "World!"])
+                .single()
+                .equals("Hello");
+        });
+    }
+}
+
+mod nth_from_end {
+    use super::*;
+
+    #[test]
+    fn succeeds() {
+        let vec = vec!["First", "Second", "Third"];
+        assert_that(vec.clone()).nth_from_end(0).equals("Third");
+        assert_that(vec.clone()).nth_from_end(1).equals("Second");
+        assert_that(vec).nth_from_end(2).equals("First");
+    }
+
+    #[test]
+    fn fails_when_too_short() {
+        failing_assertion!({
+            assert_that(vec!["First", "Second", "Third"])
+                .nth_from_end(3)
+                .equals("Hello World!");
+        });
+    }
+
+    #[test]
+    fn fails_with_huge_index() {
+        failing_assertion!({
+            assert_that(vec!["First"])
+                .nth_from_end(usize::MAX)
+                .equals("Hello World!");
+        });
+    }
+}
+
 mod combined {
     use super::*;
 
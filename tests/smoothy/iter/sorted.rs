@@ -0,0 +1,108 @@
+use crate::failing_assertion;
+use smoothy::{assert_that, IteratorAssertion};
+
+mod ascending {
+    use super::*;
+
+    #[test]
+    fn succeeds() {
+        assert_that([1, 2, 2, 3]).is_sorted();
+        assert_that(["a", "b", "c"]).is_sorted();
+    }
+
+    #[test]
+    fn succeeds_when_empty_or_single() {
+        assert_that(Vec::<i32>::new()).is_sorted();
+        assert_that([42]).is_sorted();
+    }
+
+    #[test]
+    fn can_be_chained_with_connectors() {
+        assert_that([1, 2, 3])
+            .is_sorted()
+            .and()
+            .is_strictly_sorted();
+    }
+
+    #[test]
+    fn fails() {
+        failing_assertion!({
+            assert_that([1, 2, 5, 3, 4]).is_sorted();
+        });
+    }
+
+    #[test]
+    fn fails_when_items_cannot_be_compared() {
+        failing_assertion!({
+            assert_that([1.0, f64::NAN, 2.0]).is_sorted();
+        });
+    }
+}
+
+mod descending {
+    use super::*;
+
+    #[test]
+    fn succeeds() {
+        assert_that([3, 2, 2, 1]).is_sorted_descending();
+    }
+
+    #[test]
+    fn fails() {
+        failing_assertion!({
+            assert_that([3, 1, 2]).is_sorted_descending();
+        });
+    }
+}
+
+mod strictly {
+    use super::*;
+
+    #[test]
+    fn succeeds() {
+        assert_that([1, 2, 3]).is_strictly_sorted();
+    }
+
+    #[test]
+    fn fails_with_equal_items() {
+        failing_assertion!({
+            assert_that([1, 2, 2, 3]).is_strictly_sorted();
+        });
+    }
+}
+
+mod by {
+    use super::*;
+
+    #[test]
+    fn succeeds() {
+        assert_that(["a", "bb", "cc", "ddd"])
+            .is_sorted_by(|first, second| first.len() <= second.len());
+    }
+
+    #[test]
+    fn fails() {
+        failing_assertion!({
+            assert_that(["a", "ddd", "bb"])
+                .is_sorted_by(|first, second| first.len() <= second.len());
+        });
+    }
+}
+
+mod by_key {
+    use super::*;
+
+    #[test]
+    fn succeeds() {
+        assert_that([("Alice", 3), ("Bob", 5), ("Carol", 5)])
+            .is_sorted_by_key(|(_name, score)| *score);
+    }
+
+    #[test]
+    fn fails() {
+        failing_assertion!({
+            assert_that([("Alice", 3), ("Bob", 8), ("Carol", 5)])
+                .is_sorted_by_key(|(_name, score)| *score);
+        });
+    }
+}
@@ -0,0 +1,125 @@
+use smoothy::{assert_that, EqualityAssertion, IteratorAssertion};
+
+#[derive(Debug)]
+struct User {
+    name: String,
+    age: u8,
+}
+
+fn users() -> Vec<User> {
+    vec![
+        User {
+            name: "Alice".to_string(),
+            age: 32,
+        },
+        User {
+            name: "Bob".to_string(),
+            age: 27,
+        },
+        User {
+            name: "Carol".to_string(),
+            age: 27,
+        },
+    ]
+}
+
+mod extracting {
+    use super::*;
+
+    #[test]
+    fn maps_every_item() {
+        assert_that(users()).extracting(|user| user.name).is(vec![
+            "Alice".to_string(),
+            "Bob".to_string(),
+            "Carol".to_string(),
+        ]);
+    }
+
+    #[test]
+    fn can_be_asserted_further() {
+        assert_that(users())
+            .extracting(|user| user.age)
+            .contains_only([27, 27, 32])
+            .and()
+            .max()
+            .is(32);
+    }
+
+    #[test]
+    fn keeps_empty_iterables_empty() {
+        assert_that(Vec::<User>::new())
+            .extracting(|user| user.age)
+            .is_empty();
+    }
+}
+
+mod filtered {
+    use super::*;
+
+    #[test]
+    fn keeps_matching_items() {
+        assert_that([1, 2, 3, 4, 5])
+            .filtered(|x| x % 2 == 0)
+            .is(vec![2, 4]);
+    }
+
+    #[test]
+    fn can_filter_everything() {
+        assert_that(users())
+            .filtered(|user| user.age > 100)
+            .is_empty();
+    }
+}
+
+mod flattened {
+    use super::*;
+
+    #[test]
+    fn concatenates_nested_iterables() {
+        assert_that(vec![vec![1, 2], vec![], vec![3]])
+            .flattened()
+            .is(vec![1, 2, 3]);
+    }
+
+    #[test]
+    fn flattens_options() {
+        assert_that([Some(1), None, Some(3)])
+            .flattened()
+            .is(vec![1, 3]);
+    }
+}
+
+mod sorted {
+    use super::*;
+
+    #[test]
+    fn sorts_ascending() {
+        assert_that([3, 1, 2]).sorted().is(vec![1, 2, 3]);
+    }
+
+    #[test]
+    fn sorts_by_key_stable() {
+        assert_that(users())
+            .sorted_by_key(|user| user.age)
+            .extracting(|user| user.name)
+            .is(vec![
+                "Bob".to_string(),
+                "Carol".to_string(),
+                "Alice".to_string(),
+            ]);
+    }
+}
+
+mod reversed {
+    use super::*;
+
+    #[test]
+    fn reverses_the_order() {
+        assert_that([1, 2, 3]).reversed().is(vec![3, 2, 1]);
+    }
+
+    #[test]
+    fn can_be_chained() {
+        assert_that([3, 1, 2]).sorted().reversed().first().is(3);
+    }
+}
@@ -0,0 +1,99 @@
+use crate::failing_assertion;
+use smoothy::{assert_that, EqualityAssertion, IteratorAssertion};
+
+mod has_unique_elements {
+    use super::*;
+
+    #[test]
+    fn succeeds() {
+        assert_that([1, 2, 3]).has_unique_elements();
+        assert_that(Vec::<i32>::new()).has_unique_elements();
+    }
+
+    #[test]
+    fn works_with_partial_eq_only() {
+        assert_that([1.0, 2.0, 3.0]).has_unique_elements();
+    }
+
+    #[test]
+    fn fails() {
+        failing_assertion!({
+            assert_that([1, 2, 1, 3, 2, 1]).has_unique_elements();
+        });
+    }
+}
+
+mod by_key {
+    use super::*;
+
+    #[test]
+    fn succeeds() {
+        assert_that([(1, "alice"), (2, "bob"), (3, "alice")])
+            .has_unique_elements_by_key(|(id, _name)| *id);
+    }
+
+    #[test]
+    fn fails() {
+        failing_assertion!({
+            assert_that([(1, "alice"), (2, "bob"), (3, "alice")])
+                .has_unique_elements_by_key(|(_id, name)| *name);
+        });
+    }
+}
+
+mod by_hash {
+    use super::*;
+
+    #[test]
+    fn succeeds() {
+        assert_that(0..10_000).has_unique_elements_by_hash();
+        assert_that(Vec::<i32>::new()).has_unique_elements_by_hash();
+    }
+
+    #[test]
+    fn fails_in_order_of_first_occurrence() {
+        failing_assertion!({
+            assert_that(["c", "a", "b", "a", "c", "a"]).has_unique_elements_by_hash();
+        });
+    }
+}
+
+mod duplicates {
+    use super::*;
+
+    #[test]
+    fn in_order_of_first_occurrence() {
+        assert_that([3, 1, 2, 1, 3, 1]).duplicates().is(vec![3, 1]);
+    }
+
+    #[test]
+    fn is_empty_without_duplicates() {
+        assert_that([1, 2, 3]).duplicates().is_empty();
+    }
+
+    #[test]
+    fn can_be_asserted_further() {
+        assert_that(["a", "b", "a"])
+            .duplicates()
+            .contains("a")
+            .and()
+            .size()
+            .is(1);
+    }
+}
+
+mod duplicates_by_hash {
+    use super::*;
+
+    #[test]
+    fn in_order_of_first_occurrence() {
+        assert_that([3, 1, 2, 1, 3, 1])
+            .duplicates_by_hash()
+            .is(vec![3, 1]);
+    }
+
+    #[test]
+    fn is_empty_without_duplicates() {
+        assert_that(0..10_000).duplicates_by_hash().is_empty();
+    }
+}
@@ -2,14 +2,23 @@
 
 mod accessors;
 mod boolean;
+mod bytes;
+#[cfg(feature = "chrono")]
+mod chrono;
+mod duration;
 mod equality;
 mod file;
+mod format;
+mod integer;
 mod iter;
 mod json;
 mod option;
 mod path;
 mod result;
 mod string;
+mod time;
+#[cfg(feature = "time")]
+mod time_crate;
 
 #[macro_export]
 #[allow(clippy::missing_panics_doc, clippy::unwrap_used, clippy::shadow_reuse)]
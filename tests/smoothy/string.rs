@@ -1,3 +1,21 @@
+mod ansi;
 mod contains;
+mod ends_with;
+#[cfg(feature = "unicode-normalization")]
+mod equals_normalized;
+mod glob;
+mod ignoring_case;
+mod ignoring_whitespace;
+mod is_blank;
+mod is_empty;
+mod is_not_empty;
+mod length;
+mod lines;
 mod matches;
+mod occurrences;
+mod parsed;
+#[cfg(feature = "regex")]
+mod patterns;
+mod split;
 mod starts_with;
+mod template;
@@ -0,0 +1,114 @@
+use crate::failing_assertion;
+use smoothy::{assert_that, AnsiColor, AnsiStyle, EqualityAssertion, StringAssertion};
+
+const OUTPUT: &str = "\u{1b}[1;31merror\u{1b}[0m: file \u{1b}[4mnot\u{1b}[24m found";
+
+mod without_ansi {
+    use super::*;
+
+    #[test]
+    fn removes_sgr_sequences() {
+        assert_that(OUTPUT)
+            .without_ansi()
+            .equals("error: file not found");
+    }
+
+    #[test]
+    fn removes_other_csi_and_osc_sequences() {
+        assert_that(
+            "\u{1b}[2K\u{1b}[1Gdone \u{1b}]8;;https://example.com\u{1b}\\link\u{1b}]8;;\u{7}!"
+                .to_string(),
+        )
+        .without_ansi()
+        .equals("done link!");
+    }
+
+    #[test]
+    fn removes_sequences_with_intermediate_bytes() {
+        assert_that("\u{1b}(Bplain\u{1b})0 \u{1b}#8text\u{1b}%G")
+            .without_ansi()
+            .equals("plain text");
+    }
+
+    #[test]
+    fn keeps_plain_text() {
+        assert_that("plain").without_ansi().equals("plain");
+    }
+}
+
+mod has_ansi_style {
+    use super::*;
+
+    #[test]
+    fn succeeds() {
+        assert_that(OUTPUT)
+            .has_ansi_style("error", AnsiStyle::Bold)
+            .and()
+            .has_ansi_style("err", AnsiStyle::Foreground(AnsiColor::Red))
+            .and()
+            .has_ansi_style("not", AnsiStyle::Underline);
+    }
+
+    #[test]
+    fn succeeds_with_extended_colors() {
+        assert_that(
+            "\u{1b}[38;5;208mwarn\u{1b}[39m \u{1b}[48;2;0;128;255mbox\u{1b}[49m \u{1b}[92mok",
+        )
+        .has_ansi_style("warn", AnsiStyle::Foreground(AnsiColor::Fixed(208)))
+        .and()
+        .has_ansi_style("box", AnsiStyle::Background(AnsiColor::Rgb(0, 128, 255)))
+        .and()
+        .has_ansi_style("ok", AnsiStyle::Foreground(AnsiColor::BrightGreen));
+    }
+
+    #[test]
+    fn succeeds_with_colon_separated_colors() {
+        assert_that("\u{1b}[38:2::255:0:0mred\u{1b}[39m \u{1b}[48:2:0:128:255mbox\u{1b}[49m \u{1b}[38:5:208mwarn")
+            .has_ansi_style("red", AnsiStyle::Foreground(AnsiColor::Rgb(255, 0, 0)))
+            .and()
+            .has_ansi_style("box", AnsiStyle::Background(AnsiColor::Rgb(0, 128, 255)))
+            .and()
+            .has_ansi_style("warn", AnsiStyle::Foreground(AnsiColor::Fixed(208)));
+    }
+
+    #[test]
+    fn succeeds_with_unparsable_codes_skipped() {
+        assert_that("\u{1b}[1mbold\u{1b}[999;3mstill bold")
+            .has_ansi_style("still bold", AnsiStyle::Bold)
+            .and()
+            .has_ansi_style("still bold", AnsiStyle::Italic);
+    }
+
+    #[test]
+    fn fails_when_style_was_reset_by_omitted_code() {
+        failing_assertion!({
+            assert_that("\u{1b}[1mbold\u{1b}[;3mitalic").has_ansi_style("italic", AnsiStyle::Bold);
+        });
+    }
+
+    #[test]
+    fn succeeds_when_any_occurrence_has_style() {
+        assert_that("error \u{1b}[1merror").has_ansi_style("error", AnsiStyle::Bold);
+    }
+
+    #[test]
+    fn fails_when_style_is_missing() {
+        failing_assertion!({
+            assert_that(OUTPUT).has_ansi_style("error:", AnsiStyle::Bold);
+        });
+    }
+
+    #[test]
+    fn fails_when_style_was_reset() {
+        failing_assertion!({
+            assert_that(OUTPUT).has_ansi_style("found", AnsiStyle::Underline);
+        });
+    }
+
+    #[test]
+    fn fails_when_text_is_missing() {
+        failing_assertion!({
+            assert_that(OUTPUT).has_ansi_style("warning", AnsiStyle::Bold);
+        });
+    }
+}
@@ -25,3 +25,18 @@ fn fails() {
         assert_that("Hello World").contains("BlaFasel");
     });
 }
+
+#[test]
+fn fails_with_closest_match() {
+    failing_assertion!({
+        assert_that("Error: failed to connect: connection refused (os error 111)")
+            .contains("Connection refused");
+    });
+}
+
+#[test]
+fn fails_with_closest_match_missing_characters() {
+    failing_assertion!({
+        assert_that("Error: connection refused").contains("connection refused!");
+    });
+}
@@ -0,0 +1,41 @@
+use crate::failing_assertion;
+use smoothy::{assert_that, StringAssertion};
+
+#[allow(clippy::unnecessary_to_owned)]
+#[test]
+fn succeeds_with_str() {
+    assert_that("Hello World")
+        .ends_with("World")
+        .and()
+        .ends_with("World".to_string());
+}
+
+#[allow(clippy::unnecessary_to_owned)]
+#[test]
+fn succeeds_with_string() {
+    assert_that("Hello World".to_string())
+        .ends_with("World")
+        .and()
+        .ends_with("World".to_string());
+}
+
+#[test]
+fn fails() {
+    failing_assertion!({
+        assert_that("Hello World").ends_with("BlaFasel");
+    });
+}
+
+#[test]
+fn fails_with_escaped_invisible_characters() {
+    failing_assertion!({
+        assert_that("Hello World\u{a0}\r\n").ends_with("World\n");
+    });
+}
+
+#[test]
+fn fails_with_closest_match() {
+    failing_assertion!({
+        assert_that("Hello Wrold").ends_with("World");
+    });
+}
@@ -0,0 +1,35 @@
+use crate::failing_assertion;
+use smoothy::{assert_that, StringAssertion};
+
+#[test]
+fn succeeds_with_precomposed_and_decomposed() {
+    assert_that("caf\u{e9}")
+        .equals_normalized("cafe\u{301}")
+        .and()
+        .equals_normalized("caf\u{e9}");
+    assert_that(String::from("cafe\u{301}")).equals_normalized("caf\u{e9}");
+}
+
+#[test]
+fn fails() {
+    failing_assertion!({
+        assert_that("cafe\u{301}").equals_normalized("Cafe");
+    });
+}
+
+#[test]
+fn succeeds_with_decomposed_form() {
+    assert_that("cafe\u{301}")
+        .equals_normalized_nfd("caf\u{e9}")
+        .and()
+        .equals_normalized_nfd("cafe\u{301}");
+    assert_that(String::from("\u{212b}ngstr\u{f6}m"))
+        .equals_normalized_nfd("A\u{30a}ngstro\u{308}m");
+}
+
+#[test]
+fn fails_with_decomposed_form() {
+    failing_assertion!({
+        assert_that("caf\u{e9}").equals_normalized_nfd("Cafe");
+    });
+}
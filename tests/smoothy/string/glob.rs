@@ -0,0 +1,88 @@
+use crate::failing_assertion;
+use smoothy::{assert_that, StringAssertion};
+
+mod matches_glob {
+    use super::*;
+
+    #[allow(clippy::unnecessary_to_owned)]
+    #[test]
+    fn succeeds_with_wildcards() {
+        assert_that("user-alice-01.log")
+            .matches_glob("user-*-??.log")
+            .and()
+            .matches_glob("*".to_string())
+            .and()
+            .matches_glob("user-alice-01.log");
+        assert_that(String::new()).matches_glob("*");
+        assert_that("grüße/straße").matches_glob("gr??e/*");
+    }
+
+    #[test]
+    fn succeeds_with_character_classes() {
+        assert_that("user-alice-01.log")
+            .matches_glob("user-[a-z]*-[0-9][0-9].[!tx]og")
+            .and()
+            .matches_glob("user-[^0-9]*.log")
+            .and()
+            .matches_glob("[]u]ser-*");
+        assert_that("a-b").matches_glob("a[-]b");
+        assert_that("a]b").matches_glob("a[\\]]b");
+    }
+
+    #[test]
+    fn succeeds_with_escaped_characters() {
+        assert_that("what?*[x]").matches_glob("what\\?\\*\\[x]");
+    }
+
+    #[test]
+    fn fails() {
+        failing_assertion!({
+            assert_that("user-alice-01.txt").matches_glob("user-*-[0-9][0-9].log");
+        });
+    }
+
+    #[test]
+    fn fails_when_value_is_longer() {
+        failing_assertion!({
+            assert_that("user-alice-01.log.gz").matches_glob("user-*.log");
+        });
+    }
+
+    #[test]
+    fn fails_with_unclosed_character_class() {
+        failing_assertion!({
+            assert_that("user-alice").matches_glob("user-[a-z*");
+        });
+    }
+
+    #[test]
+    fn fails_with_dangling_escape() {
+        failing_assertion!({
+            assert_that("user-alice").matches_glob("user-*\\");
+        });
+    }
+}
+
+mod matches_wildcard {
+    use super::*;
+
+    #[test]
+    fn succeeds() {
+        assert_that("user-alice-01.log")
+            .matches_wildcard("user-*-??.log")
+            .and()
+            .matches_wildcard("*alice*");
+    }
+
+    #[test]
+    fn matches_brackets_literally() {
+        assert_that("[INFO] started").matches_wildcard("[INFO] *");
+    }
+
+    #[test]
+    fn fails() {
+        failing_assertion!({
+            assert_that("user-alice-1.log").matches_wildcard("user-*-??.log");
+        });
+    }
+}
@@ -0,0 +1,63 @@
+use crate::failing_assertion;
+use smoothy::{assert_that, StringAssertion};
+
+mod equals_ignoring_case {
+    use super::*;
+
+    #[test]
+    fn succeeds() {
+        assert_that("Content-Type")
+            .equals_ignoring_case("content-type")
+            .and()
+            .equals_ignoring_case(String::from("CONTENT-TYPE"));
+    }
+
+    #[test]
+    fn succeeds_with_unicode_case_folding() {
+        assert_that("Straße").equals_ignoring_case("STRASSE");
+        assert_that(String::from("ΟΔΥΣΣΕΥΣ")).equals_ignoring_case("Οδυσσευς");
+        assert_that("Ärger").equals_ignoring_case("äRGER");
+    }
+
+    #[test]
+    fn fails() {
+        failing_assertion!({
+            assert_that("Straße").equals_ignoring_case("STRASSE X");
+        });
+    }
+}
+
+mod contains_ignoring_case {
+    use super::*;
+
+    #[test]
+    fn succeeds() {
+        assert_that("SELECT * FROM users")
+            .contains_ignoring_case("from")
+            .and()
+            .contains_ignoring_case("Users");
+    }
+
+    #[test]
+    fn fails() {
+        failing_assertion!({
+            assert_that("SELECT * FROM users").contains_ignoring_case("where");
+        });
+    }
+}
+
+mod starts_with_ignoring_case {
+    use super::*;
+
+    #[test]
+    fn succeeds() {
+        assert_that(String::from("SELECT * FROM users")).starts_with_ignoring_case("select");
+    }
+
+    #[test]
+    fn fails() {
+        failing_assertion!({
+            assert_that("SELECT * FROM users").starts_with_ignoring_case("insert");
+        });
+    }
+}
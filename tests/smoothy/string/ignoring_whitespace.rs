@@ -0,0 +1,89 @@
+use crate::failing_assertion;
+use smoothy::{assert_that, StringAssertion};
+
+mod equals_ignoring_whitespace {
+    use super::*;
+
+    #[test]
+    fn succeeds() {
+        assert_that("  SELECT *\n\tFROM users\r\n  WHERE id = 1 ")
+            .equals_ignoring_whitespace("SELECT * FROM users WHERE id = 1")
+            .and()
+            .equals_ignoring_whitespace(String::from("SELECT *  FROM\nusers WHERE id = 1"));
+    }
+
+    #[test]
+    fn fails() {
+        failing_assertion!({
+            assert_that("SELECT *\n  FROM users\n  WHERE id = 1")
+                .equals_ignoring_whitespace("SELECT * FROM users WHERE id = 2");
+        });
+    }
+}
+
+mod equals_ignoring_all_whitespace {
+    use super::*;
+
+    #[test]
+    fn succeeds() {
+        assert_that("<ul>\n  <li>Item</li>\n</ul>")
+            .equals_ignoring_all_whitespace("<ul><li>Item</li></ul>");
+    }
+
+    #[test]
+    fn fails() {
+        failing_assertion!({
+            assert_that("<ul>\n  <li>Item</li>\n</ul>")
+                .equals_ignoring_all_whitespace("<ul><li>Other</li></ul>");
+        });
+    }
+}
+
+mod equals_ignoring_line_endings {
+    use super::*;
+
+    #[test]
+    fn succeeds() {
+        assert_that("first\r\nsecond\rthird\n")
+            .equals_ignoring_line_endings("first\nsecond\nthird\n")
+            .and()
+            .equals_ignoring_line_endings("first\r\nsecond\r\nthird\r\n");
+    }
+
+    #[test]
+    fn fails() {
+        failing_assertion!({
+            assert_that("first\r\nsecond\r\nthird").equals_ignoring_line_endings("first\nthird\n");
+        });
+    }
+}
+
+mod equals_ignoring_indentation {
+    use super::*;
+
+    #[test]
+    fn succeeds() {
+        let generated = "
+            fn main() {
+                println!();
+
+            }";
+
+        assert_that(generated)
+            .equals_ignoring_indentation("\nfn main() {\n    println!();\n\n}")
+            .and()
+            .equals_ignoring_indentation("\n  fn main() {\n      println!();\n  \n  }");
+    }
+
+    #[test]
+    fn fails() {
+        failing_assertion!({
+            let generated = "
+            fn main() {
+            println!();
+            }";
+
+            assert_that(generated).equals_ignoring_indentation("\nfn main() {\n    println!();\n}");
+        });
+    }
+}
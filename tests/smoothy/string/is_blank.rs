@@ -0,0 +1,22 @@
+use crate::failing_assertion;
+use smoothy::{assert_that, StringAssertion};
+
+#[test]
+fn succeeds() {
+    assert_that("").is_blank().and().is_empty();
+    assert_that(String::from(" \t\r\n\u{a0}")).is_blank();
+}
+
+#[test]
+fn fails() {
+    failing_assertion!({
+        assert_that(" Hello\tWorld ").is_blank();
+    });
+}
+
+#[test]
+fn fails_with_zero_width_space() {
+    failing_assertion!({
+        assert_that(" \u{200b} ").is_blank();
+    });
+}
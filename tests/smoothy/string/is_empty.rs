@@ -0,0 +1,22 @@
+use crate::failing_assertion;
+use smoothy::{assert_that, StringAssertion};
+
+#[test]
+fn succeeds() {
+    assert_that("").is_empty();
+    assert_that(String::new()).is_empty();
+}
+
+#[test]
+fn fails() {
+    failing_assertion!({
+        assert_that("Hello World").is_empty();
+    });
+}
+
+#[test]
+fn fails_with_invisible_characters() {
+    failing_assertion!({
+        assert_that("\u{200b}").is_empty();
+    });
+}
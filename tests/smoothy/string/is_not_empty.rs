@@ -0,0 +1,15 @@
+use crate::failing_assertion;
+use smoothy::{assert_that, StringAssertion};
+
+#[test]
+fn succeeds() {
+    assert_that("Hello World").is_not_empty();
+    assert_that(String::from(" ")).is_not_empty();
+}
+
+#[test]
+fn fails() {
+    failing_assertion!({
+        assert_that("").is_not_empty();
+    });
+}
@@ -0,0 +1,65 @@
+use crate::failing_assertion;
+use smoothy::{assert_that, EqualityAssertion, StringAssertion};
+
+mod has_length {
+    use super::*;
+
+    #[test]
+    fn succeeds() {
+        assert_that("Grüße").has_length(5).and().has_length(5);
+        assert_that(String::new()).has_length(0);
+    }
+
+    #[test]
+    fn fails() {
+        failing_assertion!({
+            assert_that("Grüße\n").has_length(5);
+        });
+    }
+}
+
+mod has_byte_length {
+    use super::*;
+
+    #[test]
+    fn succeeds() {
+        assert_that("Grüße").has_byte_length(7);
+    }
+
+    #[test]
+    fn fails() {
+        failing_assertion!({
+            assert_that("Grüße").has_byte_length(5);
+        });
+    }
+}
+
+mod accessors {
+    use super::*;
+
+    #[test]
+    fn length() {
+        assert_that("Grüße").length().is(5);
+    }
+
+    #[test]
+    fn byte_length() {
+        assert_that("Grüße").byte_length().is(7);
+    }
+
+    #[test]
+    fn measure_the_same_as_the_assertions() {
+        assert_that("Grüße").has_length(5).and().length().is(5);
+        assert_that("Grüße").has_length(5).and().char_count().is(5);
+        assert_that("Grüße")
+            .has_byte_length(7)
+            .and()
+            .byte_length()
+            .is(7);
+    }
+
+    #[test]
+    fn char_count() {
+        assert_that(String::from("Grüße")).char_count().is(5);
+    }
+}
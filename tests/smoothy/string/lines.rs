@@ -0,0 +1,91 @@
+use crate::failing_assertion;
+use smoothy::{assert_that, EqualityAssertion, IteratorAssertion, StringAssertion};
+
+mod accessor {
+    use super::*;
+
+    #[test]
+    fn succeeds() {
+        assert_that("first\r\nsecond\n\nfourth\n").lines().is(vec![
+            String::from("first"),
+            String::from("second"),
+            String::new(),
+            String::from("fourth"),
+        ]);
+    }
+
+    #[test]
+    fn can_use_iterator_assertions() {
+        assert_that(String::from("warning: unused\nerror: failed"))
+            .lines()
+            .any_match(|line| line.starts_with("error"));
+    }
+}
+
+mod has_line_count {
+    use super::*;
+
+    #[test]
+    fn succeeds() {
+        assert_that("first\nsecond\n")
+            .has_line_count(2)
+            .and()
+            .has_line_count(2);
+        assert_that("").has_line_count(0);
+    }
+
+    #[test]
+    fn fails() {
+        failing_assertion!({
+            assert_that("first\nsecond\n").has_line_count(3);
+        });
+    }
+}
+
+mod contains_line {
+    use super::*;
+
+    #[test]
+    fn succeeds() {
+        assert_that("Compiling smoothy\r\nFinished release\n").contains_line("Finished release");
+    }
+
+    #[test]
+    fn fails_for_partial_line() {
+        failing_assertion!({
+            assert_that("Compiling smoothy\nFinished release").contains_line("Finished");
+        });
+    }
+}
+
+mod equals_lines {
+    use super::*;
+
+    #[test]
+    fn succeeds_ignoring_line_endings() {
+        assert_that("first\r\nsecond\r\n")
+            .equals_lines("first\nsecond")
+            .and()
+            .equals_lines(String::from("first\nsecond\n"));
+    }
+
+    #[test]
+    fn fails() {
+        failing_assertion!({
+            assert_that("first\nsecond\nthird").equals_lines("first\nother\nthird\nfourth");
+        });
+    }
+
+    #[test]
+    fn fails_with_separate_hunks() {
+        failing_assertion!({
+            let actual = (1..=20)
+                .map(|n| n.to_string())
+                .collect::<Vec<_>>()
+                .join("\n");
+            let expected = actual.replace("\n2\n", "\ntwo\n").replace("\n19\n", "\n");
+
+            assert_that(actual).equals_lines(expected);
+        });
+    }
+}
@@ -0,0 +1,120 @@
+use crate::failing_assertion;
+use smoothy::{assert_that, EqualityAssertion, StringAssertion};
+
+const LOG: &str = "starting\nretrying\nretrying\nlistening";
+
+mod contains_times {
+    use super::*;
+
+    #[allow(clippy::unnecessary_to_owned)]
+    #[test]
+    fn succeeds() {
+        assert_that(LOG)
+            .contains_times("retrying", 2)
+            .and()
+            .contains_times("ing".to_string(), 4)
+            .and()
+            .contains_times("ERROR", 0);
+    }
+
+    #[test]
+    fn counts_non_overlapping_occurrences() {
+        assert_that("aaaa").contains_times("aa", 2);
+    }
+
+    #[test]
+    fn fails() {
+        failing_assertion!({
+            assert_that(LOG).contains_times("retrying", 1);
+        });
+    }
+}
+
+mod count {
+    use super::*;
+
+    #[test]
+    fn succeeds() {
+        assert_that(LOG.to_string()).occurrences("retrying").is(2);
+        assert_that(LOG).occurrences("ERROR").is(0);
+    }
+}
+
+mod contains_all_of {
+    use super::*;
+
+    #[test]
+    fn succeeds() {
+        assert_that(LOG)
+            .contains_all_of(["listening", "starting"])
+            .and()
+            .contains_all_of(vec!["retrying".to_string()])
+            .and()
+            .contains_all_of(Vec::<&str>::new());
+    }
+
+    #[test]
+    fn fails() {
+        failing_assertion!({
+            assert_that(LOG).contains_all_of(["listening", "ERROR", "starting"]);
+        });
+    }
+}
+
+mod contains_any_of {
+    use super::*;
+
+    #[test]
+    fn succeeds() {
+        assert_that(LOG).contains_any_of(["ERROR", "listening"]);
+    }
+
+    #[test]
+    fn fails() {
+        failing_assertion!({
+            assert_that(LOG).contains_any_of(["ERROR", "WARN"]);
+        });
+    }
+
+    #[test]
+    fn fails_without_patterns() {
+        failing_assertion!({
+            assert_that(LOG).contains_any_of(Vec::<&str>::new());
+        });
+    }
+}
+
+mod contains_in_order {
+    use super::*;
+
+    #[test]
+    fn succeeds() {
+        assert_that(LOG)
+            .contains_in_order(["starting", "retrying", "retrying", "listening"])
+            .and()
+            .contains_in_order(["starting", "listening"])
+            .and()
+            .contains_in_order(Vec::<&str>::new());
+    }
+
+    #[test]
+    fn fails_when_out_of_order() {
+        failing_assertion!({
+            assert_that(LOG).contains_in_order(["listening", "starting"]);
+        });
+    }
+
+    #[test]
+    fn fails_when_occurring_too_few_times() {
+        failing_assertion!({
+            assert_that(LOG).contains_in_order(["retrying", "retrying", "retrying"]);
+        });
+    }
+
+    #[test]
+    fn fails_when_missing() {
+        failing_assertion!({
+            assert_that(LOG).contains_in_order(["starting", "ERROR", "listening"]);
+        });
+    }
+}
@@ -0,0 +1,29 @@
+use crate::failing_assertion;
+use smoothy::{assert_that, EqualityAssertion, StringAssertion};
+use std::net::Ipv4Addr;
+
+#[test]
+fn succeeds_with_str() {
+    assert_that("42").parsed::<u16>().is(42);
+}
+
+#[test]
+fn succeeds_with_string() {
+    assert_that("127.0.0.1".to_string())
+        .parsed::<Ipv4Addr>()
+        .is(Ipv4Addr::LOCALHOST);
+}
+
+#[test]
+fn fails_with_parse_error() {
+    failing_assertion!({
+        let _parsed = assert_that("-1").parsed::<u16>();
+    });
+}
+
+#[test]
+fn fails_with_escaped_invisible_characters() {
+    failing_assertion!({
+        let _parsed = assert_that("42\n").parsed::<u16>();
+    });
+}
@@ -0,0 +1,157 @@
+use crate::failing_assertion;
+use regex::Regex;
+use smoothy::{
+    assert_that, CapturesAssertion, EqualityAssertion, IteratorAssertion, StringAssertion,
+};
+use std::collections::BTreeMap;
+
+mod matches_pattern {
+    use super::*;
+
+    #[test]
+    fn succeeds() {
+        assert_that("I categorically deny having triskaidekaphobia.")
+            .matches_pattern(r"\b\w{13}\b")
+            .and()
+            .matches_pattern(String::from("^I"));
+    }
+
+    #[test]
+    fn fails() {
+        failing_assertion!({
+            assert_that("I deny having fun.").matches_pattern(r"\b\w{13}\b");
+        });
+    }
+
+    #[test]
+    fn fails_with_invalid_pattern() {
+        failing_assertion!({
+            assert_that("I deny having fun.").matches_pattern(r"\b\w{13\b");
+        });
+    }
+}
+
+mod does_not_match {
+    use super::*;
+
+    #[test]
+    fn succeeds() {
+        assert_that("Finished without problems")
+            .does_not_match(&Regex::new(r"(?i)\berror\b").unwrap());
+    }
+
+    #[test]
+    fn fails() {
+        failing_assertion!({
+            assert_that("Error: 1 error occurred")
+                .does_not_match(&Regex::new(r"(?i)\berror\b").unwrap());
+        });
+    }
+}
+
+mod is_fully_matched_by {
+    use super::*;
+
+    #[test]
+    fn succeeds() {
+        assert_that("2026-10-19").is_fully_matched_by(&Regex::new(r"\d{4}-\d{2}-\d{2}").unwrap());
+    }
+
+    #[test]
+    fn succeeds_with_alternation() {
+        assert_that("ab").is_fully_matched_by(&Regex::new("a|ab").unwrap());
+    }
+
+    #[test]
+    fn fails_for_partial_match() {
+        failing_assertion!({
+            assert_that("Date: 2026-10-19")
+                .is_fully_matched_by(&Regex::new(r"\d{4}-\d{2}-\d{2}").unwrap());
+        });
+    }
+}
+
+mod find_all {
+    use super::*;
+
+    #[test]
+    fn succeeds() {
+        assert_that("user-1, user-22 and user-333")
+            .find_all(&Regex::new(r"user-\d+").unwrap())
+            .is(vec![
+                String::from("user-1"),
+                String::from("user-22"),
+                String::from("user-333"),
+            ]);
+    }
+
+    #[test]
+    fn is_empty_without_matches() {
+        assert_that("nobody")
+            .find_all(&Regex::new(r"user-\d+").unwrap())
+            .is_empty();
+    }
+}
+
+mod captures {
+    use super::*;
+
+    #[test]
+    fn succeeds_with_positional_and_named_groups() {
+        let regex = Regex::new(r"(?<user>\w+) logged in from ([\d.]+)").unwrap();
+
+        assert_that("alice logged in from 10.0.0.1")
+            .captures(&regex)
+            .name("user")
+            .equals("alice");
+        assert_that("alice logged in from 10.0.0.1")
+            .captures(&regex)
+            .group(2)
+            .equals("10.0.0.1");
+        assert_that("alice logged in from 10.0.0.1")
+            .captures(&regex)
+            .group(0)
+            .equals("alice logged in from 10.0.0.1");
+        assert_that("alice logged in from 10.0.0.1")
+            .captures(&regex)
+            .group_count()
+            .is(3);
+    }
+
+    #[test]
+    fn named_groups_leave_out_groups_that_did_not_participate() {
+        assert_that("alice logged in")
+            .captures(&Regex::new(r"(?<user>\w+) logged in(?: from (?<ip>[\d.]+))?").unwrap())
+            .named_groups()
+            .is(BTreeMap::from([(
+                String::from("user"),
+                String::from("alice"),
+            )]));
+    }
+
+    #[test]
+    fn fails_without_match() {
+        failing_assertion!({
+            let _captures =
+                assert_that("alice logged out").captures(&Regex::new(r"(\w+) logged in").unwrap());
+        });
+    }
+
+    #[test]
+    fn fails_for_unknown_group() {
+        failing_assertion!({
+            let _group = assert_that("alice logged in from 10.0.0.1")
+                .captures(&Regex::new(r"(?<user>\w+) logged in from ([\d.]+)").unwrap())
+                .name("ip");
+        });
+    }
+
+    #[test]
+    fn fails_for_group_that_did_not_participate() {
+        failing_assertion!({
+            let _group = assert_that("id=42")
+                .captures(&Regex::new(r"id=(\d+)|name=(\w+)").unwrap())
+                .group(2);
+        });
+    }
+}
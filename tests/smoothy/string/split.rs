@@ -0,0 +1,34 @@
+use smoothy::{assert_that, EqualityAssertion, IteratorAssertion, StringAssertion};
+
+mod at_separator {
+    use super::*;
+
+    #[test]
+    fn succeeds() {
+        assert_that("id,name,,email").split(",").is(vec![
+            String::from("id"),
+            String::from("name"),
+            String::new(),
+            String::from("email"),
+        ]);
+    }
+
+    #[test]
+    fn succeeds_with_multi_character_separator() {
+        assert_that(String::from("a -> b -> c"))
+            .split(" -> ")
+            .size()
+            .is(3);
+    }
+}
+
+mod split_whitespace {
+    use super::*;
+
+    #[test]
+    fn succeeds() {
+        assert_that("  Hello \t World\n")
+            .split_whitespace()
+            .contains_only(["World", "Hello"]);
+    }
+}
@@ -25,3 +25,10 @@ fn fails() {
         assert_that("Hello World").starts_with("BlaFasel");
     });
 }
+
+#[test]
+fn fails_with_closest_match() {
+    failing_assertion!({
+        assert_that("Helo World").starts_with("Hello");
+    });
+}
@@ -0,0 +1,109 @@
+use crate::failing_assertion;
+use smoothy::{assert_that, CapturesAssertion, EqualityAssertion, StringAssertion};
+use std::collections::BTreeMap;
+
+const RESPONSE: &str = "Created order 7f3a9c at 2026-10-19T13:37:00Z";
+
+#[test]
+fn succeeds_with_named_placeholders() {
+    let captures = assert_that(RESPONSE).matches_template("Created order {id} at {timestamp}");
+
+    captures.name("id").equals("7f3a9c");
+}
+
+#[test]
+fn captures_placeholders_by_position() {
+    assert_that(RESPONSE.to_string())
+        .matches_template("Created order {id} at {timestamp}")
+        .group(0)
+        .equals("7f3a9c");
+}
+
+#[test]
+fn captures_placeholders_as_map() {
+    assert_that(RESPONSE)
+        .matches_template("Created {*} {id} at {timestamp}")
+        .named_groups()
+        .is(BTreeMap::from([
+            (String::from("id"), String::from("7f3a9c")),
+            (
+                String::from("timestamp"),
+                String::from("2026-10-19T13:37:00Z"),
+            ),
+        ]));
+}
+
+#[test]
+fn ignores_anonymous_placeholders() {
+    assert_that(RESPONSE)
+        .matches_template("{*} order {id} at {*}")
+        .group_count()
+        .is(1);
+}
+
+#[test]
+fn last_placeholder_extends_up_to_final_literal() {
+    assert_that("a-b-c.log")
+        .matches_template("{first}-{rest}.log")
+        .name("rest")
+        .equals("b-c");
+}
+
+#[test]
+fn succeeds_with_escaped_braces() {
+    assert_that(r#"{"id": 42}"#)
+        .matches_template(r#"{{"id": {id}}}"#)
+        .name("id")
+        .equals("42");
+}
+
+#[test]
+fn succeeds_without_placeholders() {
+    assert_that("Hello World")
+        .matches_template("Hello World")
+        .group_count()
+        .is(0);
+}
+
+#[test]
+fn fails_when_leading_text_diverges() {
+    failing_assertion!({
+        let _captures = assert_that("Deleted order 7f3a9c").matches_template("Created order {id}");
+    });
+}
+
+#[test]
+fn fails_when_inner_text_is_missing() {
+    failing_assertion!({
+        let _captures =
+            assert_that("Created order 7f3a9c").matches_template("Created order {id} at {*}");
+    });
+}
+
+#[test]
+fn fails_when_trailing_text_diverges() {
+    failing_assertion!({
+        let _captures = assert_that("user-1.txt").matches_template("user-{id}.log");
+    });
+}
+
+#[test]
+fn fails_when_value_is_longer() {
+    failing_assertion!({
+        let _captures = assert_that("Hello World!").matches_template("Hello World");
+    });
+}
+
+#[test]
+fn fails_with_unclosed_placeholder() {
+    failing_assertion!({
+        let _captures = assert_that(RESPONSE).matches_template("Created order {id");
+    });
+}
+
+#[test]
+fn fails_with_duplicate_placeholder() {
+    failing_assertion!({
+        let _captures = assert_that(RESPONSE).matches_template("{word} order {word} at {*}");
+    });
+}
@@ -0,0 +1,177 @@
+use crate::failing_assertion;
+use smoothy::{assert_that, DurationAssertion, EqualityAssertion, TimeAssertion};
+use std::time::{Duration, Instant, SystemTime, UNIX_EPOCH};
+
+fn timestamp(seconds: u64) -> SystemTime {
+    UNIX_EPOCH + Duration::from_secs(seconds)
+}
+
+mod is_before {
+    use super::*;
+
+    #[test]
+    fn succeeds_with_system_time() {
+        assert_that(timestamp(1)).is_before(timestamp(2));
+    }
+
+    #[test]
+    fn succeeds_with_instant() {
+        let now = Instant::now();
+        assert_that(now).is_before(now + Duration::from_millis(1));
+    }
+
+    #[test]
+    fn fails() {
+        failing_assertion!({
+            assert_that(timestamp(1_790_000_000)).is_before(timestamp(1_700_000_000));
+        });
+    }
+
+    #[test]
+    fn fails_when_equal() {
+        failing_assertion!({
+            assert_that(timestamp(1_700_000_000)).is_before(timestamp(1_700_000_000));
+        });
+    }
+}
+
+mod is_after {
+    use super::*;
+
+    #[test]
+    fn succeeds() {
+        assert_that(timestamp(2)).is_after(timestamp(1));
+    }
+
+    #[test]
+    fn fails() {
+        failing_assertion!({
+            assert_that(UNIX_EPOCH - Duration::from_millis(1_500)).is_after(timestamp(0));
+        });
+    }
+
+    #[test]
+    fn fails_when_equal() {
+        failing_assertion!({
+            assert_that(timestamp(1_700_000_000)).is_after(timestamp(1_700_000_000));
+        });
+    }
+}
+
+mod is_between {
+    use super::*;
+
+    #[test]
+    fn succeeds() {
+        assert_that(timestamp(5)).is_between(timestamp(1), timestamp(10));
+    }
+
+    #[test]
+    fn succeeds_on_bounds() {
+        assert_that(timestamp(1))
+            .is_between(timestamp(1), timestamp(10))
+            .and()
+            .is_between(timestamp(0), timestamp(1));
+    }
+
+    #[test]
+    fn fails() {
+        failing_assertion!({
+            assert_that(timestamp(1_761_000_000))
+                .is_between(timestamp(1_700_000_000), timestamp(1_760_000_000));
+        });
+    }
+}
+
+mod is_within {
+    use super::*;
+
+    #[test]
+    fn succeeds() {
+        assert_that(timestamp(100))
+            .is_within(Duration::from_secs(5))
+            .of(timestamp(95))
+            .and()
+            .is_within(Duration::from_secs(5))
+            .of(timestamp(105));
+    }
+
+    #[test]
+    fn can_be_chained() {
+        assert_that(timestamp(100))
+            .is_within(Duration::ZERO)
+            .of(timestamp(100))
+            .and()
+            .equals(timestamp(100));
+    }
+
+    #[test]
+    fn fails() {
+        failing_assertion!({
+            assert_that(UNIX_EPOCH + Duration::from_millis(1_761_000_000_123))
+                .is_within(Duration::from_millis(100))
+                .of(timestamp(1_761_000_000));
+        });
+    }
+}
+
+mod is_in_the_past {
+    use super::*;
+
+    #[test]
+    fn succeeds() {
+        assert_that(SystemTime::now() - Duration::from_secs(1)).is_in_the_past();
+        assert_that(Instant::now()).is_in_the_past();
+    }
+
+    #[test]
+    fn fails() {
+        failing_assertion!(
+            {
+                assert_that(SystemTime::now() + Duration::from_secs(3_600)).is_in_the_past();
+            },
+            "to be in the past"
+        );
+    }
+}
+
+mod is_in_the_future {
+    use super::*;
+
+    #[test]
+    fn succeeds() {
+        assert_that(SystemTime::now() + Duration::from_secs(60)).is_in_the_future();
+        assert_that(Instant::now() + Duration::from_secs(60)).is_in_the_future();
+    }
+
+    #[test]
+    fn fails() {
+        failing_assertion!(
+            {
+                assert_that(timestamp(0)).is_in_the_future();
+            },
+            "1970-01-01T00:00:00Z"
+        );
+    }
+}
+
+mod elapsed {
+    use super::*;
+
+    #[test]
+    fn succeeds() {
+        assert_that(SystemTime::now() - Duration::from_secs(60))
+            .elapsed()
+            .is_longer_than(Duration::from_secs(59));
+    }
+
+    #[test]
+    fn fails_when_in_the_future() {
+        failing_assertion!(
+            {
+                let _elapsed = assert_that(Instant::now() + Duration::from_secs(60)).elapsed();
+            },
+            "but is ahead of now by"
+        );
+    }
+}
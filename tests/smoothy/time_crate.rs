@@ -0,0 +1,164 @@
+use crate::failing_assertion;
+use smoothy::{
+    assert_that, DurationAssertion, EqualityAssertion, OffsetDateTimeAssertion, TimeAssertion,
+    TimeCalendarAssertion, TimeParseAssertion,
+};
+use std::time::Duration;
+use time::{Date, Month, OffsetDateTime, UtcOffset, Weekday};
+
+#[allow(clippy::unwrap_used)]
+fn date(year: i32, month: Month, day: u8) -> Date {
+    Date::from_calendar_date(year, month, day).unwrap()
+}
+
+/// 2026-10-19T12:00:00Z
+#[allow(clippy::unwrap_used)]
+fn noon() -> OffsetDateTime {
+    OffsetDateTime::from_unix_timestamp(1_792_411_200).unwrap()
+}
+
+#[allow(clippy::unwrap_used)]
+fn cest() -> UtcOffset {
+    UtcOffset::from_hms(2, 0, 0).unwrap()
+}
+
+mod time_assertions {
+    use super::*;
+
+    #[test]
+    fn succeed_with_offset_date_time() {
+        assert_that(noon())
+            .is_before(noon() + time::Duration::SECOND)
+            .and()
+            .is_after(noon() - time::Duration::SECOND)
+            .and()
+            .is_between(noon(), noon())
+            .and()
+            .is_within(Duration::from_secs(60))
+            .of(noon() + time::Duration::MINUTE);
+    }
+
+    #[test]
+    fn succeed_with_date() {
+        assert_that(date(2026, Month::October, 19))
+            .is_after(date(2026, Month::October, 18))
+            .and()
+            .is_within(Duration::from_secs(86_400))
+            .of(date(2026, Month::October, 20));
+        assert_that(date(2000, Month::January, 1)).is_in_the_past();
+    }
+
+    #[test]
+    fn succeed_relative_to_now() {
+        assert_that(OffsetDateTime::now_utc() - time::Duration::MINUTE)
+            .elapsed()
+            .is_longer_than(Duration::from_secs(59));
+        assert_that(OffsetDateTime::now_utc() + time::Duration::MINUTE).is_in_the_future();
+    }
+
+    #[test]
+    fn fails() {
+        failing_assertion!({
+            assert_that(noon()).is_before(noon() - time::Duration::HOUR);
+        });
+    }
+}
+
+mod calendar {
+    use super::*;
+
+    #[test]
+    fn accessors() {
+        assert_that(noon()).year().is(2026);
+        assert_that(noon()).month().is(Month::October);
+        assert_that(noon()).day().is(19);
+        assert_that(noon()).weekday().is(Weekday::Monday);
+    }
+
+    #[test]
+    fn accessors_use_the_offset_of_the_date_time() {
+        let date_time = (noon() + time::Duration::hours(11)).to_offset(cest());
+
+        assert_that(date_time).day().is(20);
+        assert_that(date_time).weekday().is(Weekday::Tuesday);
+    }
+
+    #[test]
+    fn is_same_day_as_succeeds() {
+        assert_that(noon()).is_same_day_as(date(2026, Month::October, 19));
+    }
+
+    #[test]
+    fn is_same_day_as_accepts_date_times() {
+        assert_that(date(2026, Month::October, 19)).is_same_day_as(noon());
+    }
+
+    #[test]
+    fn is_same_day_as_fails() {
+        failing_assertion!({
+            assert_that(noon()).is_same_day_as(date(2025, Month::October, 19));
+        });
+    }
+}
+
+mod is_same_instant_as {
+    use super::*;
+
+    #[test]
+    fn succeeds() {
+        assert_that(noon()).is_same_instant_as(noon().to_offset(cest()));
+    }
+
+    #[test]
+    fn fails() {
+        failing_assertion!({
+            assert_that(noon()).is_same_instant_as(noon().replace_offset(cest()));
+        });
+    }
+}
+
+mod parsing {
+    use super::*;
+
+    #[test]
+    fn to_time_offset_date_time_succeeds() {
+        assert_that("2026-10-19T14:00:00+02:00")
+            .to_time_offset_date_time()
+            .is_same_instant_as(noon());
+    }
+
+    #[test]
+    fn to_time_offset_date_time_fails() {
+        failing_assertion!({
+            let _parsed = assert_that("2026-10-19 14:00").to_time_offset_date_time();
+        });
+    }
+
+    #[test]
+    fn to_time_date_succeeds() {
+        assert_that(String::from("19.10.2026"))
+            .to_time_date("[day].[month].[year]")
+            .equals(date(2026, Month::October, 19));
+    }
+
+    #[test]
+    fn to_time_date_fails() {
+        failing_assertion!({
+            let _parsed = assert_that("2026-10-19").to_time_date("[day].[month].[year]");
+        });
+    }
+
+    #[test]
+    fn to_time_date_fails_with_invalid_format() {
+        failing_assertion!({
+            let _parsed = assert_that("2026-10-19").to_time_date("[unknown]");
+        });
+    }
+
+    #[test]
+    fn to_time_primitive_date_time_succeeds() {
+        assert_that("2026-10-19 13:37")
+            .to_time_primitive_date_time("[year]-[month]-[day] [hour]:[minute]")
+            .is_same_day_as(date(2026, Month::October, 19));
+    }
+}